@@ -0,0 +1,28 @@
+use alloc::vec::Vec;
+
+use ::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::testing::{MockOwner, Tracker};
+use crate::ForeignVec;
+
+/// Generates native and foreign buffers alike. Foreign buffers own a copy of their elements
+/// via a [`MockOwner`] of a new [`Tracker`].
+impl<'a, T> Arbitrary<'a> for ForeignVec<MockOwner, T>
+where
+    T: Arbitrary<'a> + Clone + Send + Sync + 'static,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let foreign = bool::arbitrary(u)?;
+        let data = Vec::<T>::arbitrary(u)?;
+        Ok(if foreign {
+            Tracker::new().import(&data)
+        } else {
+            data.into()
+        })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(bool::size_hint(depth), Vec::<T>::size_hint(depth))
+    }
+}
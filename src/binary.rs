@@ -0,0 +1,268 @@
+//! Contains [`ForeignBinary`], a variable-length binary view composed of offsets and values.
+use crate::ForeignVec;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for i32 {}
+    impl Sealed for i64 {}
+}
+
+/// Types that can be used as offsets of a [`ForeignBinary`] (`i32` and `i64`).
+pub trait Offset: sealed::Sealed + Copy + Ord + Default {
+    /// Converts this offset to `usize`, or returns `None` if it is negative or does not
+    /// fit in `usize` (e.g. a large `i64` on 32-bit targets).
+    fn to_usize(self) -> Option<usize>;
+}
+
+impl Offset for i32 {
+    #[inline]
+    fn to_usize(self) -> Option<usize> {
+        usize::try_from(self).ok()
+    }
+}
+
+impl Offset for i64 {
+    #[inline]
+    fn to_usize(self) -> Option<usize> {
+        usize::try_from(self).ok()
+    }
+}
+
+/// Errors returned when the offsets of a [`ForeignBinary`] are invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryError {
+    /// The offsets are empty; they must contain at least one element.
+    EmptyOffsets,
+    /// The offset at `index` is negative.
+    NegativeOffset {
+        /// The position of the offending offset
+        index: usize,
+    },
+    /// The offset at `index` does not fit in `usize`.
+    Overflow {
+        /// The position of the offending offset
+        index: usize,
+    },
+    /// The offset at `index` is smaller than the previous offset.
+    NotMonotonic {
+        /// The position of the offending offset
+        index: usize,
+    },
+    /// The last offset is larger than the number of values.
+    OutOfBounds {
+        /// The last offset
+        offset: usize,
+        /// The number of values
+        values: usize,
+    },
+}
+
+impl core::fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::EmptyOffsets => write!(f, "offsets must contain at least one element"),
+            Self::NegativeOffset { index } => write!(f, "offset at {index} is negative"),
+            Self::Overflow { index } => write!(f, "offset at {index} does not fit in usize"),
+            Self::NotMonotonic { index } => {
+                write!(f, "offset at {index} is smaller than its predecessor")
+            }
+            Self::OutOfBounds { offset, values } => write!(
+                f,
+                "last offset ({offset}) is larger than the number of values ({values})"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for BinaryError {}
+
+/// Checks that `offsets` are non-empty, representable as `usize`, monotonically increasing
+/// and that the last offset is in bounds of `values_len`.
+fn validate<O: Offset>(offsets: &[O], values_len: usize) -> Result<(), BinaryError> {
+    if offsets.is_empty() {
+        return Err(BinaryError::EmptyOffsets);
+    }
+    // the checks are on the converted offsets, which are the ones used to slice the values
+    let mut last = 0;
+    for (index, offset) in offsets.iter().enumerate() {
+        let offset = match offset.to_usize() {
+            Some(offset) => offset,
+            None if *offset < O::default() => return Err(BinaryError::NegativeOffset { index }),
+            None => return Err(BinaryError::Overflow { index }),
+        };
+        if offset < last && index > 0 {
+            return Err(BinaryError::NotMonotonic { index });
+        }
+        last = offset;
+    }
+    if last > values_len {
+        return Err(BinaryError::OutOfBounds {
+            offset: last,
+            values: values_len,
+        });
+    }
+    Ok(())
+}
+
+/// An immutable sequence of variable-length byte slices, e.g. an Arrow `Binary` or
+/// `LargeBinary` array, composed of offsets and values that may be foreign.
+///
+/// The `i`th slot is `values[offsets[i]..offsets[i + 1]]`. The offsets are validated
+/// on construction, so that accessing any slot is always in bounds.
+pub struct ForeignBinary<D, O: Offset> {
+    offsets: ForeignVec<D, O>,
+    values: ForeignVec<D, u8>,
+}
+
+impl<D, O: Offset> ForeignBinary<D, O> {
+    /// Creates a new [`ForeignBinary`] from its `offsets` and `values`.
+    /// # Errors
+    /// This function errors iff `offsets` is empty, has negative offsets or offsets that
+    /// do not fit in `usize`, is not monotonically increasing, or its last offset is larger
+    /// than `values.len()`.
+    pub fn try_new(
+        offsets: ForeignVec<D, O>,
+        values: ForeignVec<D, u8>,
+    ) -> Result<Self, BinaryError> {
+        validate(&offsets, values.len())?;
+        Ok(Self { offsets, values })
+    }
+
+    /// Creates a new [`ForeignBinary`] from its `offsets` and `values` without validating them.
+    /// # Safety
+    /// This function is safe iff [`ForeignBinary::try_new`] would succeed.
+    #[inline]
+    pub unsafe fn new_unchecked(offsets: ForeignVec<D, O>, values: ForeignVec<D, u8>) -> Self {
+        Self { offsets, values }
+    }
+
+    /// Returns the number of slots.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns whether there are no slots.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value of slot `i`.
+    /// # Panics
+    /// This function panics iff `i >= self.len()`.
+    #[inline]
+    pub fn value(&self, i: usize) -> &[u8] {
+        assert!(i < self.len(), "index out of bounds");
+        // Safety: just checked
+        unsafe { self.value_unchecked(i) }
+    }
+
+    /// Returns the value of slot `i`, or `None` if `i` is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&[u8]> {
+        // Safety: just checked
+        (i < self.len()).then(|| unsafe { self.value_unchecked(i) })
+    }
+
+    /// Returns the value of slot `i` without bounds checking.
+    /// # Safety
+    /// This function is safe iff `i < self.len()`.
+    #[inline]
+    pub unsafe fn value_unchecked(&self, i: usize) -> &[u8] {
+        // the offsets fit in `usize`, are monotonic and in bounds by construction
+        let start = self.offsets.get_unchecked(i).to_usize().unwrap_unchecked();
+        let end = self
+            .offsets
+            .get_unchecked(i + 1)
+            .to_usize()
+            .unwrap_unchecked();
+        self.values.get_unchecked(start..end)
+    }
+
+    /// Returns an iterator over the values of this binary.
+    #[inline]
+    pub fn iter(&self) -> BinaryIter<'_, D, O> {
+        BinaryIter {
+            array: self,
+            index: 0,
+            end: self.len(),
+        }
+    }
+
+    /// Returns the offsets of this binary.
+    #[inline]
+    pub fn offsets(&self) -> &ForeignVec<D, O> {
+        &self.offsets
+    }
+
+    /// Returns the values of this binary.
+    #[inline]
+    pub fn values(&self) -> &ForeignVec<D, u8> {
+        &self.values
+    }
+
+    /// Returns the offsets and values of this binary.
+    #[inline]
+    pub fn into_inner(self) -> (ForeignVec<D, O>, ForeignVec<D, u8>) {
+        (self.offsets, self.values)
+    }
+}
+
+impl<D, O: Offset> core::fmt::Debug for ForeignBinary<D, O> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, D, O: Offset> IntoIterator for &'a ForeignBinary<D, O> {
+    type Item = &'a [u8];
+    type IntoIter = BinaryIter<'a, D, O>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of a [`ForeignBinary`].
+pub struct BinaryIter<'a, D, O: Offset> {
+    array: &'a ForeignBinary<D, O>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, D, O: Offset> Iterator for BinaryIter<'a, D, O> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.index == self.end {
+            return None;
+        }
+        // Safety: `index < end <= len`
+        let value = unsafe { self.array.value_unchecked(self.index) };
+        self.index += 1;
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D, O: Offset> DoubleEndedIterator for BinaryIter<'a, D, O> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [u8]> {
+        if self.index == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // Safety: `end < len`
+        Some(unsafe { self.array.value_unchecked(self.end) })
+    }
+}
+
+impl<'a, D, O: Offset> ExactSizeIterator for BinaryIter<'a, D, O> {}
@@ -0,0 +1,238 @@
+//! Contains [`ForeignBitmap`], a bit-packed view over a [`ForeignVec<D, u8>`].
+use crate::ForeignVec;
+
+#[inline]
+fn get_bit(bytes: &[u8], i: usize) -> bool {
+    bytes[i / 8] & (1 << (i % 8)) != 0
+}
+
+/// Returns a byte whose `bits` least significant bits are set (`bits <= 8`).
+#[inline]
+fn low_mask(bits: usize) -> u8 {
+    (0xFFu16 >> (8 - bits.min(8))) as u8
+}
+
+/// An immutable sequence of bits backed by a [`ForeignVec<D, u8>`].
+///
+/// Bits are packed least-significant-bit first, as in Arrow validity bitmaps,
+/// and the bitmap may start at any bit `offset` of the underlying bytes, so that
+/// a bitmap imported from a foreign sliced array can be used without copying it.
+pub struct ForeignBitmap<D> {
+    bytes: ForeignVec<D, u8>,
+    offset: usize,
+    length: usize,
+}
+
+impl<D> ForeignBitmap<D> {
+    /// Creates a new [`ForeignBitmap`] with `length` bits starting at bit `offset` of `bytes`.
+    /// # Panics
+    /// This function panics iff `offset + length` is larger than the number of bits in `bytes`.
+    #[inline]
+    pub fn new(bytes: ForeignVec<D, u8>, offset: usize, length: usize) -> Self {
+        assert!(
+            offset
+                .checked_add(length)
+                .is_some_and(|end| end <= bytes.len().saturating_mul(8)),
+            "the bitmap must fit in its bytes"
+        );
+        Self {
+            bytes,
+            offset,
+            length,
+        }
+    }
+
+    /// Returns the number of bits in this bitmap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns whether this bitmap has no bits.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the offset, in bits, of this bitmap in its bytes.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the bytes backing this bitmap.
+    #[inline]
+    pub fn bytes(&self) -> &ForeignVec<D, u8> {
+        &self.bytes
+    }
+
+    /// Returns the bytes, offset and length of this bitmap.
+    #[inline]
+    pub fn into_inner(self) -> (ForeignVec<D, u8>, usize, usize) {
+        (self.bytes, self.offset, self.length)
+    }
+
+    /// Returns the bit at position `i`, or `None` if `i` is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<bool> {
+        (i < self.length).then(|| get_bit(&self.bytes, self.offset + i))
+    }
+
+    /// Returns the bit at position `i` without bounds checking.
+    /// # Safety
+    /// This function is safe iff `i < self.len()`.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, i: usize) -> bool {
+        let i = self.offset + i;
+        *self.bytes.get_unchecked(i / 8) & (1 << (i % 8)) != 0
+    }
+
+    /// Returns an iterator over the bits of this bitmap.
+    #[inline]
+    pub fn iter(&self) -> BitmapIter<'_> {
+        BitmapIter {
+            bytes: &self.bytes,
+            index: self.offset,
+            end: self.offset + self.length,
+        }
+    }
+
+    /// Returns an iterator over the positions of the set bits of this bitmap.
+    #[inline]
+    pub fn set_indices(&self) -> SetIndices<'_> {
+        SetIndices::new(&self.bytes, self.offset, self.length)
+    }
+
+    /// Returns the number of set bits in this bitmap.
+    pub fn count_ones(&self) -> usize {
+        if self.length == 0 {
+            return 0;
+        }
+        let end = self.offset + self.length;
+        let first = self.offset / 8;
+        let last = (end - 1) / 8;
+        let head = self.bytes[first] >> (self.offset % 8);
+        if first == last {
+            return (head & low_mask(self.length)).count_ones() as usize;
+        }
+        let middle = self.bytes[first + 1..last]
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum::<usize>();
+        let tail = self.bytes[last] & low_mask(end - last * 8);
+        head.count_ones() as usize + middle + tail.count_ones() as usize
+    }
+
+    /// Returns the number of unset bits in this bitmap (e.g. the null count of a validity).
+    #[inline]
+    pub fn count_zeros(&self) -> usize {
+        self.length - self.count_ones()
+    }
+}
+
+impl<D> core::fmt::Debug for ForeignBitmap<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, D> IntoIterator for &'a ForeignBitmap<D> {
+    type Item = bool;
+    type IntoIter = BitmapIter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the bits of a [`ForeignBitmap`].
+#[derive(Debug, Clone)]
+pub struct BitmapIter<'a> {
+    bytes: &'a [u8],
+    index: usize,
+    end: usize,
+}
+
+impl<'a> Iterator for BitmapIter<'a> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.index == self.end {
+            return None;
+        }
+        let bit = get_bit(self.bytes, self.index);
+        self.index += 1;
+        Some(bit)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> DoubleEndedIterator for BitmapIter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if self.index == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(get_bit(self.bytes, self.end))
+    }
+}
+
+impl<'a> ExactSizeIterator for BitmapIter<'a> {}
+
+/// An iterator over the positions of the set bits of a [`ForeignBitmap`].
+#[derive(Debug, Clone)]
+pub struct SetIndices<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    end: usize,
+    /// the position of the first bit of `current` in `bytes`
+    byte_start: usize,
+    /// the set bits of the current byte that were not yet yielded
+    current: u8,
+}
+
+impl<'a> SetIndices<'a> {
+    fn new(bytes: &'a [u8], offset: usize, length: usize) -> Self {
+        let end = offset + length;
+        let byte_start = offset / 8 * 8;
+        let current = if length == 0 {
+            0
+        } else {
+            let byte = bytes[offset / 8] & !low_mask(offset % 8);
+            byte & low_mask(end - byte_start)
+        };
+        Self {
+            bytes,
+            offset,
+            end,
+            byte_start,
+            current,
+        }
+    }
+}
+
+impl<'a> Iterator for SetIndices<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.byte_start += 8;
+            if self.byte_start >= self.end {
+                return None;
+            }
+            self.current = self.bytes[self.byte_start / 8] & low_mask(self.end - self.byte_start);
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.byte_start + bit - self.offset)
+    }
+}
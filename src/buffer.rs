@@ -0,0 +1,61 @@
+//! Contains [`Buffer`], the storage of a region regardless of how it is owned.
+use core::ops::Deref;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+
+use crate::{Allocation, ForeignSlice, ForeignVec};
+
+/// A region of `T` owned or borrowed in some way, e.g. a [`ForeignVec`], a [`Vec<T>`] or
+/// a `&[T]`, so that code can be generic over how its inputs are stored.
+pub trait Buffer<T>: Deref<Target = [T]> {
+    /// Returns the region as a mutable slice if it can be mutated in place, i.e. if it
+    /// is uniquely owned and writable, and `None` otherwise.
+    ///
+    /// Returns `None` by default.
+    #[inline]
+    fn try_as_mut_slice(&mut self) -> Option<&mut [T]> {
+        None
+    }
+}
+
+impl<D, T> Buffer<T> for ForeignVec<D, T> {
+    /// Native regions can be mutated in place, foreign ones cannot.
+    #[inline]
+    fn try_as_mut_slice(&mut self) -> Option<&mut [T]> {
+        match self.mode() {
+            // Safety: native regions are uniquely owned by `self`
+            Allocation::Native(_) => Some(unsafe { self.as_mut_slice_unchecked() }),
+            Allocation::Foreign(_) => None,
+        }
+    }
+}
+
+impl<T> Buffer<T> for &[T] {}
+
+impl<T> Buffer<T> for ForeignSlice<'_, T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> Buffer<T> for Vec<T> {
+    #[inline]
+    fn try_as_mut_slice(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Buffer<T> for Box<[T]> {
+    #[inline]
+    fn try_as_mut_slice(&mut self) -> Option<&mut [T]> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Buffer<T> for Arc<[T]> {
+    /// The region can be mutated in place if this is its only reference.
+    #[inline]
+    fn try_as_mut_slice(&mut self) -> Option<&mut [T]> {
+        Arc::get_mut(self)
+    }
+}
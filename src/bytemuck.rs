@@ -0,0 +1,107 @@
+use core::mem::size_of;
+use core::ptr::NonNull;
+
+use ::bytemuck::allocation::try_cast_vec;
+use ::bytemuck::{NoUninit, Pod, PodCastError};
+
+use crate::native::native_vec;
+use crate::{Allocation, ForeignVec, Owner};
+
+/// Errors returned by [`ForeignVec::try_cast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastError {
+    /// The region is not aligned to the target type.
+    Misaligned,
+    /// The region was allocated by Rust for a type with a different alignment, and
+    /// must therefore be deallocated with it.
+    AlignmentMismatch,
+    /// The size in bytes of the region is not a multiple of the size of the target type.
+    SizeMismatch,
+}
+
+impl From<PodCastError> for CastError {
+    fn from(error: PodCastError) -> Self {
+        match error {
+            PodCastError::TargetAlignmentGreaterAndInputNotAligned => Self::Misaligned,
+            PodCastError::AlignmentMismatch => Self::AlignmentMismatch,
+            PodCastError::OutputSliceWouldHaveSlop | PodCastError::SizeMismatch => {
+                Self::SizeMismatch
+            }
+        }
+    }
+}
+
+impl core::fmt::Display for CastError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Misaligned => write!(f, "the region is not aligned to the target type"),
+            Self::AlignmentMismatch => write!(
+                f,
+                "a native region can only be cast to a type with the same alignment"
+            ),
+            Self::SizeMismatch => write!(
+                f,
+                "the region's size is not a multiple of the target type's size"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for CastError {}
+
+impl<D, T: NoUninit> ForeignVec<D, T> {
+    /// Returns the region as bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        ::bytemuck::cast_slice(self)
+    }
+}
+
+impl<D, T: Pod> ForeignVec<D, T> {
+    /// Reinterprets this region as a region of `U`, without copying it.
+    ///
+    /// A foreign region can be cast to any `U` to which it is aligned and whose size
+    /// divides the size of the region, and an empty one to any `U`. A native region can only
+    /// be cast to a `U` with the same alignment as `T`, since it must be deallocated with the
+    /// layout it was allocated with.
+    /// # Errors
+    /// This function errors iff the cast is not possible, in which case the region is dropped.
+    pub fn try_cast<U: Pod>(self) -> Result<ForeignVec<D, U>, CastError>
+    where
+        D: Owner,
+    {
+        match self.mode() {
+            Allocation::Native(capacity) => {
+                let (ptr, length, _) = self.into_parts();
+                // Safety: a native region is the raw parts of a `Vec`
+                let vec = unsafe { native_vec(ptr, length, capacity) };
+                try_cast_vec(vec)
+                    .map(Into::into)
+                    .map_err(|(error, _)| error.into())
+            }
+            Allocation::Foreign(_) => {
+                let bytes = self.len() * size_of::<T>();
+                if bytes == 0 {
+                    // an empty region has no elements to align: it is stored dangling, as
+                    // empty regions are on import
+                    // Safety: the region is valid for 0 elements of `U`
+                    let mut vec = unsafe { self.cast_unchecked(0) };
+                    vec.ptr = NonNull::dangling();
+                    return Ok(vec);
+                }
+                if !(self.as_ptr() as *const U).is_aligned() {
+                    return Err(CastError::Misaligned);
+                }
+                let length = match size_of::<U>() {
+                    // `bytes` is not zero, and thus not a multiple of a zero `size`
+                    size if bytes.is_multiple_of(size) => bytes / size,
+                    _ => return Err(CastError::SizeMismatch),
+                };
+                // the region is not imported again: it remains owned by the same owner, and
+                // recorded (e.g. in the registry) as it was imported
+                // Safety: the region is valid for `length` of `U`
+                Ok(unsafe { self.cast_unchecked(length) })
+            }
+        }
+    }
+}
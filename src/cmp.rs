@@ -0,0 +1,71 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::ForeignVec;
+
+impl<D1, D2, T: PartialEq<U>, U> PartialEq<ForeignVec<D2, U>> for ForeignVec<D1, T> {
+    #[inline]
+    fn eq(&self, other: &ForeignVec<D2, U>) -> bool {
+        **self == **other
+    }
+}
+
+impl<D, T: Eq> Eq for ForeignVec<D, T> {}
+
+/// Implements `PartialEq` between [`ForeignVec`] and `$rhs` (over `U`) and between
+/// `$lhs` (over `T`) and [`ForeignVec`].
+macro_rules! impl_eq {
+    ($([$($vars:tt)*] $lhs:ty, $rhs:ty;)*) => {$(
+        impl<$($vars)* D, T: PartialEq<U>, U> PartialEq<$rhs> for ForeignVec<D, T> {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                self[..] == other[..]
+            }
+        }
+
+        impl<$($vars)* D, T: PartialEq<U>, U> PartialEq<ForeignVec<D, U>> for $lhs {
+            #[inline]
+            fn eq(&self, other: &ForeignVec<D, U>) -> bool {
+                self[..] == other[..]
+            }
+        }
+    )*};
+}
+
+impl_eq! {
+    [] [T], [U];
+    ['a,] &'a [T], &'a [U];
+    ['a,] &'a mut [T], &'a mut [U];
+    [const N: usize,] [T; N], [U; N];
+    ['a, const N: usize,] &'a [T; N], &'a [U; N];
+}
+
+#[cfg(feature = "alloc")]
+impl_eq! {
+    [] Vec<T>, Vec<U>;
+}
+
+impl<D, T: Hash> Hash for ForeignVec<D, T> {
+    /// Hashes as `[T]` (and thus as [`Vec<T>`]) does.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<D1, D2, T: PartialOrd> PartialOrd<ForeignVec<D2, T>> for ForeignVec<D1, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &ForeignVec<D2, T>) -> Option<Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<D, T: Ord> Ord for ForeignVec<D, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self).cmp(&**other)
+    }
+}
@@ -0,0 +1,195 @@
+//! CUDA memory: [`PinnedHost`], an owner of page-locked host allocations, and
+//! [`DeviceForeignVec`], a region in device memory.
+//!
+//! This module calls the CUDA runtime API (`cudaMallocHost` and `cudaFreeHost`) but does
+//! not link it: binaries using [`PinnedHost`] must link `cudart`, e.g. via
+//! `cargo:rustc-link-lib=cudart` in their build script.
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::NonNull;
+
+use crate::{ForeignVec, HeapSize, Owner};
+
+extern "C" {
+    fn cudaMallocHost(ptr: *mut *mut c_void, size: usize) -> i32;
+    fn cudaFreeHost(ptr: *mut c_void) -> i32;
+}
+
+/// An error returned by the CUDA runtime, as its `cudaError_t` code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CudaError(pub i32);
+
+impl core::fmt::Display for CudaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the CUDA runtime failed with error code {}", self.0)
+    }
+}
+
+impl core::error::Error for CudaError {}
+
+/// An owner of a page-locked ("pinned") host allocation, which is freed via `cudaFreeHost`
+/// when dropped.
+///
+/// Pinned memory is host memory, and thus regions owned by it are used as any other
+/// [`ForeignVec`]; the device can copy from and to it asynchronously.
+#[derive(Debug)]
+pub struct PinnedHost {
+    ptr: NonNull<u8>,
+    len_bytes: usize,
+}
+
+// Safety: the allocation is only freed with `self`, which owns it
+unsafe impl Send for PinnedHost {}
+
+// Safety: `&self` exposes no access to the allocation
+unsafe impl Sync for PinnedHost {}
+
+impl PinnedHost {
+    /// Allocates `len_bytes` of pinned host memory via `cudaMallocHost`.
+    /// # Errors
+    /// This function errors iff `cudaMallocHost` does.
+    pub fn alloc(len_bytes: usize) -> Result<Self, CudaError> {
+        let mut ptr = core::ptr::null_mut();
+        // Safety: `ptr` is a valid location for the pointer of the allocation
+        match unsafe { cudaMallocHost(&mut ptr, len_bytes) } {
+            0 => Ok(Self {
+                // `cudaMallocHost` returns a null pointer for zero bytes
+                ptr: NonNull::new(ptr as *mut u8).unwrap_or(NonNull::dangling()),
+                len_bytes,
+            }),
+            error => Err(CudaError(error)),
+        }
+    }
+
+    /// Takes ownership of a pinned host allocation.
+    /// # Safety
+    /// This function is safe iff `ptr` was returned by `cudaMallocHost` (or `cudaHostAlloc`)
+    /// for `len_bytes` and is not freed by anyone else.
+    #[inline]
+    pub unsafe fn from_raw(ptr: NonNull<u8>, len_bytes: usize) -> Self {
+        Self { ptr, len_bytes }
+    }
+
+    /// Returns a pointer to the allocation.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns the size of the allocation in bytes.
+    #[inline]
+    pub fn len_bytes(&self) -> usize {
+        self.len_bytes
+    }
+
+    /// Returns a [`ForeignVec`] over the (uninitialized) allocation, as in
+    /// [`ForeignVec::from_foreign_uninit`], with as many `T` as fit in it.
+    /// # Panics
+    /// This function panics iff the allocation is not aligned to `T`.
+    #[inline]
+    pub fn into_uninit<T>(self) -> ForeignVec<Self, MaybeUninit<T>> {
+        let length = self.len_bytes.checked_div(size_of::<T>()).unwrap_or(0);
+        let ptr = self.as_ptr() as *const T;
+        // Safety: the allocation is valid for `len_bytes` while `self` is alive
+        unsafe { ForeignVec::from_foreign_uninit(ptr, length, self) }
+    }
+}
+
+impl Drop for PinnedHost {
+    fn drop(&mut self) {
+        if self.len_bytes > 0 {
+            // Safety: the allocation is owned by `self`; an error can only be reported
+            unsafe { cudaFreeHost(self.ptr.as_ptr() as *mut c_void) };
+        }
+    }
+}
+
+impl Owner for PinnedHost {}
+
+impl HeapSize for PinnedHost {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        self.len_bytes
+    }
+}
+
+/// A region in device memory, kept alive by an owner `D` (e.g. a handle to a `cudaMalloc`
+/// allocation), as a [`ForeignVec`] is.
+///
+/// Device memory cannot be accessed from the host, and thus this deliberately does not
+/// implement `Deref`: it only exposes the device pointer and length, e.g. to be passed
+/// to kernels and asynchronous copies.
+pub struct DeviceForeignVec<D, T> {
+    ptr: *const T,
+    length: usize,
+    owner: D,
+    data: PhantomData<T>,
+}
+
+// Safety: `DeviceForeignVec` behaves as a `ForeignVec` whose region is never accessed
+// from the host
+unsafe impl<D: Send, T: Send> Send for DeviceForeignVec<D, T> {}
+
+// Safety: as above
+unsafe impl<D: Sync, T: Sync> Sync for DeviceForeignVec<D, T> {}
+
+impl<D: Owner, T> DeviceForeignVec<D, T> {
+    /// Takes ownership of a region in device memory.
+    /// # Safety
+    /// This function is safe if and only if `ptr` is a device pointer valid for `length`
+    /// for as long as `owner` is alive.
+    /// # Implementation
+    /// This function leaks if and only if `owner` does not deallocate the region when dropped.
+    #[inline]
+    pub unsafe fn from_device(ptr: *const T, length: usize, owner: D) -> Self {
+        Self {
+            ptr,
+            length,
+            owner,
+            data: PhantomData,
+        }
+    }
+}
+
+impl<D, T> DeviceForeignVec<D, T> {
+    /// Returns the device pointer of the region.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    /// Returns the number of elements of the region.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns whether the region is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the size of the region in bytes, e.g. for asynchronous copies.
+    #[inline]
+    pub fn len_bytes(&self) -> usize {
+        self.length * size_of::<T>()
+    }
+
+    /// Returns a reference to the owner of the region.
+    #[inline]
+    pub fn owner(&self) -> &D {
+        &self.owner
+    }
+}
+
+impl<D: core::fmt::Debug, T> core::fmt::Debug for DeviceForeignVec<D, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DeviceForeignVec")
+            .field("ptr", &self.ptr)
+            .field("len", &self.length)
+            .field("owner", &self.owner)
+            .finish()
+    }
+}
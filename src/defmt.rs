@@ -0,0 +1,25 @@
+use ::defmt::{write, Format, Formatter};
+
+use crate::ForeignVec;
+
+/// The maximum number of elements written by the [`Format`] of a [`ForeignVec`].
+const MAX_FORMAT_LEN: usize = 32;
+
+/// Writes the elements as a list, e.g. `[1, 2]`, regardless of how the region was allocated.
+/// Buffers longer than 32 elements are truncated to their first 32 elements, e.g.
+/// `[0, 1, …, 31, … 1048576 values]`, to keep logs small.
+impl<D, T: Format> Format for ForeignVec<D, T> {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "[");
+        for (i, value) in self.iter().take(MAX_FORMAT_LEN).enumerate() {
+            if i > 0 {
+                write!(f, ", ");
+            }
+            write!(f, "{}", value);
+        }
+        if self.len() > MAX_FORMAT_LEN {
+            write!(f, ", … {=usize} values", self.len());
+        }
+        write!(f, "]");
+    }
+}
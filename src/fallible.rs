@@ -0,0 +1,66 @@
+//! Fallible variants of the methods that copy regions into native memory.
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+
+use crate::{ForeignVec, VecMut};
+
+/// Returns a copy of `slice`, or an error if it cannot be allocated.
+fn try_to_vec<T: Clone>(slice: &[T]) -> Result<Vec<T>, TryReserveError> {
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(slice.len())?;
+    vec.extend_from_slice(slice);
+    Ok(vec)
+}
+
+impl<D, T: Clone> ForeignVec<D, T> {
+    /// Like [`ForeignVec::make_mut`], but returning an error instead of aborting when the
+    /// copy cannot be allocated.
+    /// # Errors
+    /// This function errors if and only if the region is foreign and the allocation of its
+    /// copy fails, in which case this buffer is unchanged.
+    pub fn try_make_mut(&mut self) -> Result<VecMut<'_, D, T>, TryReserveError> {
+        if self.is_foreign() {
+            *self = try_to_vec(self)?.into();
+        }
+        Ok(self.make_mut())
+    }
+
+    /// Like [`ForeignVec::into_native`], but returning an error instead of aborting when the
+    /// copy cannot be allocated.
+    /// # Errors
+    /// This function errors if and only if the region is foreign and the allocation of its
+    /// copy fails, in which case this buffer is returned (unchanged) with the error.
+    pub fn try_into_native(self) -> Result<Self, (Self, TryReserveError)> {
+        if !self.is_foreign() {
+            return Ok(self);
+        }
+        match try_to_vec(&self) {
+            Ok(vec) => Ok(vec.into()),
+            Err(error) => Err((self, error)),
+        }
+    }
+
+    /// Like [`ForeignVec::concat`], but returning an error instead of aborting when the
+    /// result cannot be allocated.
+    /// # Errors
+    /// This function errors if and only if the allocation of the result fails.
+    pub fn try_concat(parts: &[ForeignVec<D, T>]) -> Result<Self, TryReserveError> {
+        let mut vec = Vec::new();
+        vec.try_reserve_exact(parts.iter().map(|part| part.len()).sum())?;
+        for part in parts {
+            vec.extend_from_slice(part);
+        }
+        Ok(vec.into())
+    }
+
+    /// Like [`ForeignVec::append`], but returning an error instead of aborting when the
+    /// copy of this buffer or the additional elements cannot be allocated.
+    /// # Errors
+    /// This function errors if and only if an allocation fails, in which case the elements
+    /// of both buffers are unchanged (though this buffer may have been made native).
+    pub fn try_append<E>(&mut self, other: &mut ForeignVec<E, T>) -> Result<(), TryReserveError> {
+        self.try_make_mut()?.try_reserve(other.len())?;
+        self.append(other);
+        Ok(())
+    }
+}
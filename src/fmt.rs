@@ -0,0 +1,65 @@
+use core::fmt::{Debug, Formatter, Result};
+
+use crate::{Allocation, AllocationKind, ForeignVec};
+
+/// The maximum number of elements written by the [`Debug`] of a [`ForeignVec`], unless
+/// formatted with `{:#?}`.
+const MAX_DEBUG_LEN: usize = 4096;
+
+/// A [`Debug`] of a slice that writes at most [`MAX_DEBUG_LEN`] elements, or all of them
+/// with `{:#?}`.
+struct Elements<'a, T>(&'a [T]);
+
+impl<T: Debug> Debug for Elements<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.0.len() <= MAX_DEBUG_LEN || f.alternate() {
+            return f.debug_list().entries(self.0).finish();
+        }
+        f.debug_list()
+            .entries(&self.0[..MAX_DEBUG_LEN])
+            .entry(&format_args!("… {} values", self.0.len()))
+            .finish()
+    }
+}
+
+/// Writes the elements as a list, e.g. `[1, 2]`. Buffers longer than 4096 elements are
+/// truncated to their first 4096 elements, e.g. `[0, 1, …, 4095, … 1048576 values]`,
+/// unless formatted with `{:#?}`.
+///
+/// See [`ForeignVec::verbose`] to also write how the region was allocated.
+impl<D, T: Debug> Debug for ForeignVec<D, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Elements(self).fmt(f)
+    }
+}
+
+/// A [`Debug`] of a [`ForeignVec`] that includes how its region was allocated,
+/// returned by [`ForeignVec::verbose`].
+pub struct Verbose<'a, D, T>(&'a ForeignVec<D, T>);
+
+impl<D, T> ForeignVec<D, T> {
+    /// Returns a [`Debug`] of this buffer that includes how its region was allocated
+    /// (and its owner, if foreign), e.g.
+    /// `ForeignVec { allocation: Foreign, owner: .., len: 2, values: [1, 2] }`.
+    #[inline]
+    pub fn verbose(&self) -> Verbose<'_, D, T> {
+        Verbose(self)
+    }
+}
+
+impl<D: Debug, T: Debug> Debug for Verbose<'_, D, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut f = f.debug_struct("ForeignVec");
+        match self.0.mode() {
+            Allocation::Native(_) => f
+                .field("allocation", &AllocationKind::Native)
+                .field("capacity", &self.0.capacity()),
+            Allocation::Foreign(owner) => f
+                .field("allocation", &AllocationKind::Foreign)
+                .field("owner", owner),
+        }
+        .field("len", &self.0.len())
+        .field("values", &Elements(self.0))
+        .finish()
+    }
+}
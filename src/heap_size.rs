@@ -0,0 +1,94 @@
+//! Contains [`HeapSize`], used to account for the memory pinned by buffers.
+use core::mem::size_of;
+
+#[cfg(feature = "alloc")]
+use core::mem::size_of_val;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+
+use crate::{Allocation, ForeignVec, NoOwner};
+
+/// An owner that knows how many bytes of memory it keeps alive.
+///
+/// Owners that share a region (e.g. [`Arc`]) report the whole region from each of their
+/// clones; deduplicating shared regions is left to the caller.
+pub trait HeapSize {
+    /// Returns the number of bytes of memory kept alive by `self`, excluding `size_of_val(self)`.
+    fn heap_size(&self) -> usize;
+}
+
+impl HeapSize for () {
+    /// The region is owned by someone else.
+    #[inline]
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for NoOwner {
+    /// The region is `'static` and thus not on the heap.
+    #[inline]
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> HeapSize for Vec<T> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> HeapSize for Box<T> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        size_of_val::<T>(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> HeapSize for Arc<T> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        size_of_val::<T>(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> HeapSize for Rc<T> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        size_of_val::<T>(self)
+    }
+}
+
+impl<D, T> ForeignVec<D, T> {
+    /// Returns the number of bytes allocated natively by this buffer, i.e. its capacity in
+    /// bytes if it is native, and 0 if it is foreign (see [`ForeignVec::heap_size`]).
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        match self.mode() {
+            Allocation::Native(capacity) => capacity * size_of::<T>(),
+            Allocation::Foreign(_) => 0,
+        }
+    }
+}
+
+impl<D: HeapSize, T> HeapSize for ForeignVec<D, T> {
+    /// Returns the number of bytes kept alive by this buffer: its capacity in bytes if
+    /// it is native, and the [`HeapSize`] of its owner if it is foreign.
+    ///
+    /// A foreign owner may keep alive more than the region itself (e.g. a whole
+    /// memory-mapped file of which the region is a slice).
+    #[inline]
+    fn heap_size(&self) -> usize {
+        match self.mode() {
+            Allocation::Native(_) => self.allocated_bytes(),
+            Allocation::Foreign(owner) => owner.heap_size(),
+        }
+    }
+}
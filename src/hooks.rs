@@ -0,0 +1,70 @@
+//! Contains [`Hooks`], callbacks invoked on the lifecycle of foreign regions.
+//!
+//! Once installed via [`set_hooks`], [`Hooks::on_import`] is invoked whenever a foreign
+//! region is imported via [`ForeignVec::from_foreign`] (or [`ForeignVec::try_from_foreign`]),
+//! and [`Hooks::on_release`] when the [`ForeignVec`] that imported it drops its owner.
+//! Both receive the region as `(ptr, len_bytes)`, as it was imported.
+//!
+//! Notes:
+//! * Regions that are not allocated externally (e.g. [`ForeignVec::from_static`] and
+//!   `From<Arc<[T]>>`) are not reported, nor are buffers sharing them.
+//! * Buffers sharing a region via a clone of its owner (e.g. [`ForeignVec::clone_shared`])
+//!   report it once per buffer; deduplicating shared regions is left to the hooks.
+//! * A region is reported as released when its [`ForeignVec`] stops owning it, even
+//!   if the owner is not dropped (e.g. when it is moved to another [`ForeignVec`]).
+//!   A leaked region (see [`ForeignVec::leak`]) is never reported as released.
+//! * Every [`ForeignVec`] records the region it imported, which makes it two words larger.
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use alloc::boxed::Box;
+
+#[cfg(doc)]
+use crate::ForeignVec;
+
+/// Callbacks invoked on the lifecycle of foreign regions. See the [module](self) documentation.
+pub trait Hooks: Sync {
+    /// Invoked after a foreign region `[ptr, ptr + len_bytes[` is imported.
+    fn on_import(&self, ptr: *const u8, len_bytes: usize);
+
+    /// Invoked after the owner of a foreign region `[ptr, ptr + len_bytes[` is released.
+    fn on_release(&self, ptr: *const u8, len_bytes: usize);
+}
+
+/// The installed hooks. This is a thin pointer to a (fat) reference so that it can be
+/// loaded atomically.
+static HOOKS: AtomicPtr<&'static dyn Hooks> = AtomicPtr::new(null_mut());
+
+/// Installs `hooks`, replacing the previously installed hooks, if any.
+///
+/// Each call allocates a few bytes that are never deallocated, since other threads
+/// may still be invoking the previous hooks.
+pub fn set_hooks(hooks: &'static dyn Hooks) {
+    HOOKS.store(Box::into_raw(Box::new(hooks)), Ordering::Release);
+}
+
+/// Uninstalls the installed hooks, if any.
+pub fn clear_hooks() {
+    HOOKS.store(null_mut(), Ordering::Release);
+}
+
+#[inline]
+fn hooks() -> Option<&'static dyn Hooks> {
+    let hooks = HOOKS.load(Ordering::Acquire);
+    // Safety: non-null pointers are leaked boxes, and are thus valid for `'static`
+    (!hooks.is_null()).then(|| unsafe { *hooks })
+}
+
+#[inline]
+pub(crate) fn import(ptr: *const u8, len_bytes: usize) {
+    if let Some(hooks) = hooks() {
+        hooks.on_import(ptr, len_bytes)
+    }
+}
+
+#[inline]
+pub(crate) fn release(ptr: *const u8, len_bytes: usize) {
+    if let Some(hooks) = hooks() {
+        hooks.on_release(ptr, len_bytes)
+    }
+}
@@ -0,0 +1,219 @@
+//! Contains [`Cursor`], a position-tracking reader over a [`ForeignVec<D, u8>`].
+//!
+//! With the `std` feature, [`ForeignVec<D, u8>`] also implements `std::io::Read` itself,
+//! consuming the bytes it reads, and `std::io::Write`, appending to the buffer.
+use crate::ForeignVec;
+
+/// Declares methods reading a little-endian and a big-endian number of each type.
+macro_rules! read_numbers {
+    ($($le:ident, $be:ident => $ty:ty;)*) => {$(
+        read_numbers!(@one $le, from_le_bytes, "little", $ty);
+        read_numbers!(@one $be, from_be_bytes, "big", $ty);
+    )*};
+    (@one $name:ident, $from:ident, $endianness:literal, $ty:ty) => {
+        #[doc = concat!("Reads a ", $endianness, "-endian `", stringify!($ty), "` and advances past it, ")]
+        #[doc = "or returns `None` if too few bytes remain, in which case the position is unchanged."]
+        #[inline]
+        pub fn $name(&mut self) -> Option<$ty> {
+            self.read_array().map(<$ty>::$from)
+        }
+    };
+}
+
+/// A reader over a [`ForeignVec<D, u8>`] that tracks its position, like `std::io::Cursor`.
+///
+/// Values can be decoded from it without pointer arithmetic, e.g. via [`Cursor::read_u32_le`],
+/// regardless of their alignment in the buffer.
+///
+/// With the `std` feature, this implements `std::io::Read`, `BufRead` and `Seek`, so that
+/// foreign buffers can be passed to consumers of `impl Read` without copying them.
+pub struct Cursor<D> {
+    inner: ForeignVec<D, u8>,
+    position: usize,
+}
+
+impl<D> Cursor<D> {
+    /// Creates a new [`Cursor`] at the start of `inner`.
+    #[inline]
+    pub fn new(inner: ForeignVec<D, u8>) -> Self {
+        Self { inner, position: 0 }
+    }
+
+    /// Returns the current position of this cursor.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Sets the position of this cursor. Positions past the end are allowed and
+    /// behave as the end.
+    #[inline]
+    pub fn set_position(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// Returns the bytes that were not yet read.
+    #[inline]
+    pub fn remaining(&self) -> &[u8] {
+        &self.inner[self.position.min(self.inner.len())..]
+    }
+
+    /// Returns the next `len` bytes and advances past them, or `None` if fewer than `len`
+    /// bytes remain, in which case the position is unchanged.
+    #[inline]
+    pub fn read_slice(&mut self, len: usize) -> Option<&[u8]> {
+        let start = self.position.min(self.inner.len());
+        let slice = self.inner[start..].get(..len)?;
+        self.position = start + len;
+        Some(slice)
+    }
+
+    /// Returns the next `N` bytes and advances past them, or `None` if fewer than `N`
+    /// bytes remain, in which case the position is unchanged.
+    #[inline]
+    pub fn read_array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.read_slice(N).map(|slice| slice.try_into().unwrap())
+    }
+
+    /// Reads a `u8` and advances past it, or returns `None` if no bytes remain.
+    #[inline]
+    pub fn read_u8(&mut self) -> Option<u8> {
+        self.read_array().map(u8::from_le_bytes)
+    }
+
+    /// Reads an `i8` and advances past it, or returns `None` if no bytes remain.
+    #[inline]
+    pub fn read_i8(&mut self) -> Option<i8> {
+        self.read_array().map(i8::from_le_bytes)
+    }
+
+    read_numbers! {
+        read_u16_le, read_u16_be => u16;
+        read_i16_le, read_i16_be => i16;
+        read_u32_le, read_u32_be => u32;
+        read_i32_le, read_i32_be => i32;
+        read_u64_le, read_u64_be => u64;
+        read_i64_le, read_i64_be => i64;
+        read_f32_le, read_f32_be => f32;
+        read_f64_le, read_f64_be => f64;
+    }
+
+    /// Returns a reference to the underlying buffer.
+    #[inline]
+    pub fn get_ref(&self) -> &ForeignVec<D, u8> {
+        &self.inner
+    }
+
+    /// Returns the underlying buffer.
+    #[inline]
+    pub fn into_inner(self) -> ForeignVec<D, u8> {
+        self.inner
+    }
+}
+
+impl<D> core::fmt::Debug for Cursor<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Cursor")
+            .field("inner", &self.inner)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D> std::io::Read for Cursor<D> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = std::io::Read::read(&mut self.remaining(), buf)?;
+        self.position += read;
+        Ok(read)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        std::io::Read::read_exact(&mut self.remaining(), buf)?;
+        self.position += buf.len();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D> std::io::BufRead for Cursor<D> {
+    #[inline]
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(self.remaining())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.position += amt;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D> std::io::Seek for Cursor<D> {
+    fn seek(&mut self, style: std::io::SeekFrom) -> std::io::Result<u64> {
+        let (base, offset) = match style {
+            std::io::SeekFrom::Start(n) => {
+                self.position = n as usize;
+                return Ok(n);
+            }
+            std::io::SeekFrom::End(n) => (self.inner.len() as u64, n),
+            std::io::SeekFrom::Current(n) => (self.position as u64, n),
+        };
+        match base.checked_add_signed(offset) {
+            Some(n) => {
+                self.position = n as usize;
+                Ok(n)
+            }
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+/// Reads from the start of the buffer, removing the bytes read as in [`ForeignVec::advance`],
+/// like the `Read` of `&[u8]`. Foreign regions are not copied: reading only moves the start
+/// of the view. Native regions shift the remaining bytes on every read, so prefer
+/// [`Cursor`] to read them in small pieces.
+#[cfg(feature = "std")]
+impl<D> std::io::Read for ForeignVec<D, u8> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = std::io::Read::read(&mut &self[..], buf)?;
+        self.advance(read);
+        Ok(read)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        std::io::Read::read_exact(&mut &self[..], buf)?;
+        self.advance(buf.len());
+        Ok(())
+    }
+}
+
+/// Appends to the buffer, as the `Write` of [`Vec<u8>`](alloc::vec::Vec). A foreign region
+/// is first copied into a native allocation (and its owner released), as in
+/// [`ForeignVec::make_mut`].
+#[cfg(feature = "std")]
+impl<D> std::io::Write for ForeignVec<D, u8> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.make_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.make_mut().extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
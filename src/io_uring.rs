@@ -0,0 +1,187 @@
+//! Buffers registered with io_uring ("fixed buffers"), imported as [`ForeignVec`]s.
+//!
+//! [`FixedBuffers`] allocates buffers and exposes them as `iovec`s, to be registered with a
+//! ring (e.g. via `Submitter::register_buffers` of the `io-uring` crate). Once an operation
+//! on buffer `index` completes (e.g. `ReadFixed`), its bytes are imported without copying
+//! them via [`FixedBuffers::import`], as a [`ForeignVec`] owned by a [`RegisteredBuf`],
+//! which keeps the buffers allocated.
+//!
+//! The buffers are page-aligned by default (e.g. for `O_DIRECT` reads), and are only
+//! deallocated when the last [`FixedBuffers`] and [`RegisteredBuf`] are dropped; the
+//! application must keep one of them alive for as long as the buffers are registered.
+use core::alloc::Layout;
+use core::ptr::NonNull;
+
+use alloc::alloc::{alloc_zeroed, dealloc, handle_alloc_error};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::{Allocation, ForeignVec, Owner, SharedOwner};
+
+/// The allocations of the buffers, each of `len` bytes allocated with `layout`.
+struct Buffers {
+    ptrs: Vec<NonNull<u8>>,
+    len: usize,
+    /// The layout of each allocation, which is never zero-sized
+    layout: Layout,
+}
+
+// Safety: the buffers are only accessed through raw pointers, and deallocated with `self`
+unsafe impl Send for Buffers {}
+
+// Safety: as above
+unsafe impl Sync for Buffers {}
+
+impl Drop for Buffers {
+    fn drop(&mut self) {
+        for ptr in &self.ptrs {
+            // Safety: allocated with `layout` in `FixedBuffers::with_align`
+            unsafe { dealloc(ptr.as_ptr(), self.layout) };
+        }
+    }
+}
+
+/// A set of equally-sized buffers to be registered with io_uring. This is a cheap,
+/// reference-counted handle: clones refer to the same buffers.
+#[derive(Clone)]
+pub struct FixedBuffers {
+    buffers: Arc<Buffers>,
+}
+
+impl FixedBuffers {
+    /// Allocates `count` zeroed, page-aligned buffers of `len` bytes each.
+    pub fn new(count: u16, len: usize) -> Self {
+        // Safety: `sysconf` has no preconditions
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        Self::with_align(count, len, page_size)
+    }
+
+    /// Allocates `count` zeroed buffers of `len` bytes each, aligned to `align` bytes
+    /// (e.g. 64 for Arrow-style columns, or the logical block size for `O_DIRECT` reads).
+    /// # Panics
+    /// This function panics iff `align` is not a power of two or `len` rounded up to
+    /// `align` is larger than `isize::MAX`.
+    pub fn with_align(count: u16, len: usize, align: usize) -> Self {
+        // zero-sized allocations are not allowed
+        let layout = match Layout::from_size_align(len.max(1), align) {
+            Ok(layout) => layout,
+            Err(_) => panic!("invalid buffers of {} bytes aligned to {}", len, align),
+        };
+        let ptrs = (0..count)
+            .map(|_| {
+                // Safety: `layout` is not zero-sized
+                let ptr = unsafe { alloc_zeroed(layout) };
+                NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+            })
+            .collect();
+        Self {
+            buffers: Arc::new(Buffers { ptrs, len, layout }),
+        }
+    }
+
+    /// Returns the number of buffers.
+    #[inline]
+    pub fn count(&self) -> u16 {
+        self.buffers.ptrs.len() as u16
+    }
+
+    /// Returns the length of each buffer, in bytes.
+    #[inline]
+    pub fn buf_len(&self) -> usize {
+        self.buffers.len
+    }
+
+    /// Returns the alignment of each buffer, in bytes.
+    #[inline]
+    pub fn align(&self) -> usize {
+        self.buffers.layout.align()
+    }
+
+    /// Returns a pointer to buffer `index`, e.g. for a `ReadFixed` entry.
+    /// # Panics
+    /// This function panics iff `index >= self.count()`.
+    #[inline]
+    pub fn as_mut_ptr(&self, index: u16) -> *mut u8 {
+        self.buffers.ptrs[index as usize].as_ptr()
+    }
+
+    /// Returns the buffers as `iovec`s, in order, to be registered with a ring.
+    pub fn iovecs(&self) -> Vec<libc::iovec> {
+        self.buffers
+            .ptrs
+            .iter()
+            .map(|ptr| libc::iovec {
+                iov_base: ptr.as_ptr().cast(),
+                iov_len: self.buffers.len,
+            })
+            .collect()
+    }
+
+    /// Returns the first `len` bytes of buffer `index` as a [`ForeignVec`], without copying
+    /// them.
+    /// # Panics
+    /// This function panics iff `index >= self.count()` or `len > self.buf_len()`.
+    /// # Safety
+    /// This function is safe iff no operation writes to buffer `index` while the returned
+    /// [`ForeignVec`] (or any buffer sharing its region) is alive.
+    pub unsafe fn import(&self, index: u16, len: usize) -> ForeignVec<RegisteredBuf, u8> {
+        assert!(
+            len <= self.buf_len(),
+            "{} bytes is larger than the buffer",
+            len
+        );
+        let owner = RegisteredBuf {
+            buffers: self.clone(),
+            index,
+        };
+        ForeignVec::from_foreign(self.as_mut_ptr(index), len, owner)
+    }
+}
+
+impl core::fmt::Debug for FixedBuffers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FixedBuffers")
+            .field("count", &self.count())
+            .field("buf_len", &self.buf_len())
+            .field("align", &self.align())
+            .finish()
+    }
+}
+
+/// The owner of a region of a buffer of [`FixedBuffers`], returned by [`FixedBuffers::import`].
+#[derive(Debug, Clone)]
+pub struct RegisteredBuf {
+    buffers: FixedBuffers,
+    index: u16,
+}
+
+impl RegisteredBuf {
+    /// Returns the index of the buffer, as registered with the ring (i.e. `buf_index` of
+    /// submission queue entries).
+    #[inline]
+    pub fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the buffers this buffer belongs to.
+    #[inline]
+    pub fn buffers(&self) -> &FixedBuffers {
+        &self.buffers
+    }
+}
+
+impl Owner for RegisteredBuf {}
+
+// Safety: clones keep the same buffers allocated
+unsafe impl SharedOwner for RegisteredBuf {}
+
+impl ForeignVec<RegisteredBuf, u8> {
+    /// Returns the index of the registered buffer of this region, or `None` if it is native.
+    #[inline]
+    pub fn buf_index(&self) -> Option<u16> {
+        match self.mode() {
+            Allocation::Foreign(owner) => Some(owner.index()),
+            Allocation::Native(_) => None,
+        }
+    }
+}
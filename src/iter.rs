@@ -0,0 +1,147 @@
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+use crate::{ForeignVec, SharedOwner};
+
+#[cfg(feature = "alloc")]
+enum Inner<D, T> {
+    Native(vec::IntoIter<T>),
+    Foreign {
+        vec: ForeignVec<D, T>,
+        index: usize,
+        end: usize,
+    },
+}
+
+#[cfg(feature = "alloc")]
+/// An owning iterator over the elements of a [`ForeignVec`].
+///
+/// Native regions are consumed as by [`vec::IntoIter`]. Elements of foreign regions
+/// are cloned, and the owner is released when the iterator is dropped.
+pub struct IntoIter<D, T> {
+    inner: Inner<D, T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<D, T: Clone> Iterator for IntoIter<D, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match &mut self.inner {
+            Inner::Native(iter) => iter.next(),
+            Inner::Foreign { vec, index, end } => {
+                if index == end {
+                    return None;
+                }
+                let item = vec[*index].clone();
+                *index += 1;
+                Some(item)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Inner::Native(iter) => iter.size_hint(),
+            Inner::Foreign { index, end, .. } => (end - index, Some(end - index)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<D, T: Clone> DoubleEndedIterator for IntoIter<D, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        match &mut self.inner {
+            Inner::Native(iter) => iter.next_back(),
+            Inner::Foreign { vec, index, end } => {
+                if index == end {
+                    return None;
+                }
+                *end -= 1;
+                Some(vec[*end].clone())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<D, T: Clone> ExactSizeIterator for IntoIter<D, T> {}
+
+#[cfg(feature = "alloc")]
+impl<D, T: Clone> IntoIterator for ForeignVec<D, T> {
+    type Item = T;
+    type IntoIter = IntoIter<D, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self.try_into_vec() {
+            Ok(vec) => Inner::Native(vec.into_iter()),
+            Err(vec) => Inner::Foreign {
+                index: 0,
+                end: vec.len(),
+                vec,
+            },
+        };
+        IntoIter { inner }
+    }
+}
+
+impl<'a, D, T> IntoIterator for &'a ForeignVec<D, T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over chunks of a [`ForeignVec`], returned by [`ForeignVec::chunks_shared`].
+pub struct ChunksShared<'a, D, T> {
+    vec: &'a ForeignVec<D, T>,
+    chunks: core::slice::Chunks<'a, T>,
+}
+
+impl<'a, D, T> ChunksShared<'a, D, T> {
+    #[inline]
+    pub(crate) fn new(vec: &'a ForeignVec<D, T>, chunk_len: usize) -> Self {
+        Self {
+            vec,
+            chunks: vec.chunks(chunk_len),
+        }
+    }
+}
+
+impl<D: SharedOwner, T: Clone> Iterator for ChunksShared<'_, D, T> {
+    type Item = ForeignVec<D, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(|chunk| self.vec.share(chunk))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<D: SharedOwner, T: Clone> DoubleEndedIterator for ChunksShared<'_, D, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back().map(|chunk| self.vec.share(chunk))
+    }
+}
+
+impl<D: SharedOwner, T: Clone> ExactSizeIterator for ChunksShared<'_, D, T> {}
+
+impl<D, T: core::fmt::Debug> core::fmt::Debug for ChunksShared<'_, D, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChunksShared")
+            .field("chunks", &self.chunks)
+            .finish()
+    }
+}
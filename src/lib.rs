@@ -1,106 +1,977 @@
 #![doc = include_str!("docs.md")]
 #![deny(missing_docs)]
 #![no_std]
+#![cfg_attr(foreign_vec_nightly, feature(dropck_eyepatch))]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use core::mem::ManuallyDrop;
-use core::ops::DerefMut;
+use core::marker::PhantomData;
+use core::mem::{size_of, ManuallyDrop, MaybeUninit};
+use core::ops::Index;
+use core::ptr::NonNull;
+use core::slice::SliceIndex;
 
-use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use native::native_vec;
 
-/// Mode of deallocating memory regions
+#[cfg(all(doc, feature = "alloc"))]
+use alloc::{sync::Arc, vec::Vec};
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+pub mod binary;
+pub mod bitmap;
+mod buffer;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod cmp;
+#[cfg(feature = "cuda")]
+pub mod cuda;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "alloc")]
+mod fallible;
+mod fmt;
+mod heap_size;
+#[cfg(feature = "hooks")]
+pub mod hooks;
+pub mod io;
+#[cfg(all(feature = "io_uring", target_os = "linux"))]
+pub mod io_uring;
+mod iter;
+pub mod matrix;
+#[cfg(feature = "alloc")]
+mod native;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "alloc")]
+mod owner_handle;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "protect")]
+mod protect;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "alloc")]
+mod segmented;
+#[cfg(feature = "serde")]
+mod serde;
+mod slice;
+#[cfg(feature = "alloc")]
+mod small;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "alloc")]
+mod vec_mut;
+pub use binary::ForeignBinary;
+pub use bitmap::ForeignBitmap;
+pub use buffer::Buffer;
+#[cfg(feature = "bytemuck")]
+pub use bytemuck::CastError;
+pub use fmt::Verbose;
+pub use heap_size::HeapSize;
+pub use iter::ChunksShared;
+#[cfg(feature = "alloc")]
+pub use iter::IntoIter;
+pub use matrix::ForeignMatrix;
+#[cfg(feature = "alloc")]
+pub use owner_handle::OwnerHandle;
+#[cfg(feature = "alloc")]
+pub use segmented::SegmentedForeignVec;
+pub use slice::ForeignSlice;
+#[cfg(feature = "alloc")]
+pub use small::SmallForeignVec;
+#[cfg(feature = "alloc")]
+pub use vec_mut::VecMut;
+
+/// Mode of deallocating memory regions, as decoded from the fields of [`ForeignVec`].
 enum Allocation<D> {
-    /// Native allocation
-    Native,
+    /// Native allocation, with the capacity of its `Vec`
+    Native(usize),
     // A foreign allocator and its ref count
     Foreign(D),
 }
 
+/// How the region of a [`ForeignVec`] was allocated, returned by [`ForeignVec::allocation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllocationKind {
+    /// Allocated by a [`Vec`]: the region can be resized and is deallocated by `Vec`.
+    Native,
+    /// Allocated by a foreign allocator: the region cannot be resized and is released
+    /// when its owner is dropped.
+    Foreign,
+}
+
 /// A continuous memory region that may be allocated externally.
 ///
 /// In the most common case, this is created from [`Vec`].
 /// However, this region may also be allocated by a foreign allocator `D`
 /// and behave as `&[T]`.
+///
+/// # Thread safety
+/// [`ForeignVec<D, T>`] is [`Send`] if and only if both `D` and `T` are [`Send`], and
+/// [`Sync`] if and only if both `D` and `T` are [`Sync`]; i.e. it behaves as a `(D, Vec<T>)`.
+/// These bounds are part of the public API and do not depend on its internal representation.
+/// Note that a foreign region is released by dropping `D` in whichever thread drops the
+/// [`ForeignVec`]; owners that must be released in a specific thread must not be [`Send`].
 pub struct ForeignVec<D, T> {
     /// An implementation using an `enum` of a `Vec` or a foreign pointer is not used
     /// because `deref` is at least 50% more expensive than the deref of a `Vec`.
-    data: ManuallyDrop<Vec<T>>,
-    /// the region was allocated
-    allocation: Allocation<D>,
+    /// Instead, the region is described by `ptr` and `length` in both modes, and only
+    /// a native region is ever (re)constructed as a `Vec`.
+    ptr: NonNull<T>,
+    length: usize,
+    /// The capacity of the `Vec` of a native region, or [`FOREIGN`] for a foreign region.
+    /// Encoding the mode here (instead of in a discriminant) keeps this struct at three
+    /// words plus `D`, and `ptr` gives `Option<ForeignVec<D, T>>` a niche.
+    capacity: usize,
+    /// Initialized if and only if `capacity == FOREIGN`
+    owner: MaybeUninit<D>,
+    /// Declares that values of `T` are owned (and dropped), for dropck. Like `ptr` and `owner`,
+    /// this keeps [`ForeignVec`] covariant in `D` and `T`, as `(D, Vec<T>)` is.
+    marker: PhantomData<T>,
+    /// The foreign region as it was imported, if it was imported via [`ForeignVec::from_foreign`]
+    #[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+    imported: Option<Imported>,
 }
 
+/// A foreign region as it was imported, shared by [`hooks`], `protect` and [`registry`].
+/// Only the region is recorded with `hooks`, so that it costs two words.
+#[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+#[derive(Clone, Copy)]
+struct Imported {
+    ptr: NonNull<u8>,
+    len_bytes: usize,
+    /// Whether the region is recorded in the [`registry`]
+    #[cfg(feature = "registry")]
+    registered: bool,
+    /// The original protection of the pages of the region, if they are write-protected
+    #[cfg(feature = "protect")]
+    protected: Option<protect::Protection>,
+}
+
+/// The capacity marking a foreign region. No native region has it: the capacity of a `Vec`
+/// of non-zero-sized `T` is at most `isize::MAX`, and native regions of zero-sized `T` are
+/// stored with capacity 0 (see `native::native_capacity`).
+const FOREIGN: usize = usize::MAX;
+
+// Safety: `ForeignVec` behaves as a `Vec<T>` and an owned `D`: the region is only accessed
+// mutably through `&mut self`, and the owner is only dropped (i.e. sent) with `self`.
+unsafe impl<D: Send, T: Send> Send for ForeignVec<D, T> {}
+
+// Safety: `ForeignVec` behaves as a `Vec<T>` and an owned `D`: `&self` only exposes `&[T]`
+// and `&D`.
+unsafe impl<D: Sync, T: Sync> Sync for ForeignVec<D, T> {}
+
 impl<D, T> ForeignVec<D, T> {
+    /// Returns a new, empty, native [`ForeignVec`]. This does not allocate.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            ptr: NonNull::dangling(),
+            length: 0,
+            capacity: 0,
+            owner: MaybeUninit::uninit(),
+            marker: PhantomData,
+            #[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+            imported: None,
+        }
+    }
+
+    /// Returns this buffer, leaving an empty, native [`ForeignVec`] in its place,
+    /// as in [`core::mem::take`]. This does not allocate.
+    #[inline]
+    pub fn take(&mut self) -> Self {
+        core::mem::take(self)
+    }
+
+    /// Returns this buffer, leaving `other` in its place, as in [`core::mem::replace`].
+    #[inline]
+    pub fn replace(&mut self, other: Self) -> Self {
+        core::mem::replace(self, other)
+    }
+
     /// Takes ownership of an allocated memory region.
+    ///
+    /// `T` is [`Copy`] and thus has no `Drop`: the elements of foreign regions are never
+    /// dropped by [`ForeignVec`]. See [`ForeignVec::from_foreign_any`] for other types.
     /// # Panics
-    /// This function panics if and only if pointer is not null
+    /// This function panics if and only if [`ForeignVec::try_from_foreign`] errors, e.g.
+    /// with the `registry` feature, when the region overlaps a live region
     /// # Safety
     /// This function is safe if and only if `ptr` is valid for `length`
     /// # Implementation
     /// This function leaks if and only if `owner` does not deallocate
     /// the region `[ptr, ptr+length[` when dropped.
     #[inline]
-    pub unsafe fn from_foreign(ptr: *const T, length: usize, owner: D) -> Self {
-        assert!(!ptr.is_null());
-        // This line is technically outside the assumptions of `Vec::from_raw_parts`, since
-        // `ptr` was not allocated by `Vec`. However, one of the invariants of this struct
-        // is that we do never expose this region as a `Vec`; we only use `Vec` on it to provide
-        // immutable access to the region (via `Vec::deref` to `&[T]`).
-        let data = Vec::from_raw_parts(ptr as *mut T, length, length);
-        let data = ManuallyDrop::new(data);
+    pub unsafe fn from_foreign(ptr: *const T, length: usize, owner: D) -> Self
+    where
+        D: Owner,
+        T: Copy,
+    {
+        Self::from_foreign_any(ptr, length, owner)
+    }
 
-        Self {
-            data,
-            allocation: Allocation::Foreign(owner),
+    /// Takes ownership of an allocated memory region, like [`ForeignVec::from_foreign`],
+    /// but returning an error instead of panicking.
+    ///
+    /// A null `ptr` is accepted when `length` is zero, since many foreign APIs represent
+    /// empty regions this way. For zero-sized `T`, `ptr` is ignored.
+    /// # Errors
+    /// This function errors if and only if
+    /// * `ptr` is null and `length` is not zero, or
+    /// * `ptr` is not aligned to `T`, or
+    /// * the region is larger than `isize::MAX` bytes, or
+    /// * with the `registry` feature, the region overlaps a live region,
+    ///
+    /// in which case `owner` is dropped, except for an overlapping region: its `owner` is
+    /// leaked, since dropping it could release the region from under the live buffer.
+    /// # Safety
+    /// This function is safe if and only if `ptr` is valid for `length`
+    /// # Implementation
+    /// This function leaks if and only if `owner` does not deallocate
+    /// the region `[ptr, ptr+length[` when dropped.
+    #[inline]
+    pub unsafe fn try_from_foreign(
+        ptr: *const T,
+        length: usize,
+        owner: D,
+    ) -> Result<Self, ImportError>
+    where
+        D: Owner,
+        T: Copy,
+    {
+        Self::try_from_foreign_any(ptr, length, owner)
+    }
+
+    /// Takes ownership of an allocated memory region of any `T`, like
+    /// [`ForeignVec::from_foreign`].
+    ///
+    /// The elements of foreign regions are never dropped by [`ForeignVec`], not even when
+    /// it is truncated or advanced: `owner` is responsible for dropping them (e.g. an
+    /// `Arc<Vec<T>>`), or they are leaked.
+    /// # Panics
+    /// This function panics if and only if [`ForeignVec::try_from_foreign_any`] errors, e.g.
+    /// with the `registry` feature, when the region overlaps a live region
+    /// # Safety
+    /// This function is safe if and only if `ptr` is valid for `length`, and the elements
+    /// are initialized and not dropped nor moved out until `owner` is dropped.
+    /// # Implementation
+    /// This function leaks if and only if `owner` does not deallocate
+    /// the region `[ptr, ptr+length[` (or drop its elements) when dropped.
+    #[inline]
+    pub unsafe fn from_foreign_any(ptr: *const T, length: usize, owner: D) -> Self
+    where
+        D: Owner,
+    {
+        match Self::try_from_foreign_any(ptr, length, owner) {
+            Ok(vec) => vec,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Takes ownership of an allocated memory region of any `T`, like
+    /// [`ForeignVec::from_foreign_any`], but returning an error instead of panicking.
+    /// # Errors
+    /// As in [`ForeignVec::try_from_foreign`]
+    /// # Safety
+    /// As in [`ForeignVec::from_foreign_any`]
+    #[inline]
+    pub unsafe fn try_from_foreign_any(
+        ptr: *const T,
+        length: usize,
+        owner: D,
+    ) -> Result<Self, ImportError>
+    where
+        D: Owner,
+    {
+        Self::try_import(ptr, length, owner)
+            .map(Self::track)
+            .and_then(Self::register)
+            .map(Self::protect)
+    }
+
+    /// Takes ownership of an allocated memory region that is not reported to [`hooks`],
+    /// e.g. because it is not allocated externally.
+    /// # Panics
+    /// This function panics if and only if [`ForeignVec::try_from_foreign`] errors
+    /// # Safety
+    /// As in [`ForeignVec::from_foreign`]
+    #[inline]
+    unsafe fn import(ptr: *const T, length: usize, owner: D) -> Self
+    where
+        D: Owner,
+    {
+        match Self::try_import(ptr, length, owner) {
+            Ok(vec) => vec,
+            Err(error) => panic!("{}", error),
         }
     }
 
-    /// Returns a `Some` mutable reference of [`Vec<T>`] iff this was initialized
-    /// from a [`Vec<T>`] and `None` otherwise.
-    pub fn get_vec(&mut self) -> Option<&mut Vec<T>> {
-        match &self.allocation {
-            Allocation::Foreign(_) => None,
-            Allocation::Native => Some(self.data.deref_mut()),
+    /// [`ForeignVec::try_from_foreign`] without reporting the region to [`hooks`].
+    #[inline]
+    unsafe fn try_import(ptr: *const T, length: usize, owner: D) -> Result<Self, ImportError>
+    where
+        D: Owner,
+    {
+        Ok(Self {
+            ptr: validate_region(ptr, length)?,
+            length,
+            capacity: FOREIGN,
+            owner: MaybeUninit::new(owner),
+            marker: PhantomData,
+            #[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+            imported: None,
+        })
+    }
+
+    /// [`ForeignVec::from_foreign`] of a part of the region of `self`, shared with it via a
+    /// clone of its owner, which is thus not recorded in the [`registry`] again. It is only
+    /// reported to [`hooks`] and write-protected if `self` was imported (e.g. not for
+    /// [`ForeignVec::from_static`]).
+    /// # Safety
+    /// As in [`ForeignVec::from_foreign`]
+    #[inline]
+    unsafe fn import_shared(&self, ptr: *const T, length: usize, owner: D) -> Self
+    where
+        D: Owner,
+    {
+        let shared = Self::import(ptr, length, owner);
+        #[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+        if self.imported.is_some() {
+            return shared.track().protect();
         }
+        shared
+    }
+
+    /// Records this foreign region as imported, reporting it to [`hooks`].
+    #[inline]
+    fn track(self) -> Self {
+        #[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+        {
+            let mut this = self;
+            let (ptr, len_bytes) = (this.ptr.cast::<u8>(), this.length * size_of::<T>());
+            #[cfg(feature = "hooks")]
+            hooks::import(ptr.as_ptr(), len_bytes);
+            this.imported = Some(Imported {
+                ptr,
+                len_bytes,
+                #[cfg(feature = "registry")]
+                registered: false,
+                #[cfg(feature = "protect")]
+                protected: None,
+            });
+            this
+        }
+        #[cfg(not(any(feature = "hooks", feature = "protect", feature = "registry")))]
+        self
+    }
+
+    /// Reports this foreign region as released to [`hooks`], if it was reported as imported.
+    #[inline]
+    fn untrack(&mut self) {
+        #[cfg(feature = "hooks")]
+        if let Some(imported) = self.imported.take() {
+            hooks::release(imported.ptr.as_ptr(), imported.len_bytes);
+        }
+    }
+
+    /// Records this foreign region in the [`registry`].
+    /// # Errors
+    /// This function errors if the region overlaps a live region, in which case `self`
+    /// is leaked (after reporting its region as released to [`hooks`]).
+    #[inline]
+    fn register(self) -> Result<Self, ImportError> {
+        #[cfg(feature = "registry")]
+        {
+            let mut this = self;
+            if let Some(imported) = this.imported.filter(|imported| imported.len_bytes > 0) {
+                if !registry::register(imported.ptr.as_ptr(), imported.len_bytes) {
+                    // releasing the region would release it from under the live buffer
+                    this.untrack();
+                    core::mem::forget(this);
+                    return Err(ImportError::Overlapping);
+                }
+                this.imported = Some(Imported {
+                    registered: true,
+                    ..imported
+                });
+            }
+            Ok(this)
+        }
+        #[cfg(not(feature = "registry"))]
+        Ok(self)
+    }
+
+    /// Removes this foreign region from the [`registry`], if it was recorded.
+    #[inline]
+    fn unregister(&mut self) {
+        #[cfg(feature = "registry")]
+        if let Some(imported) = self
+            .imported
+            .as_mut()
+            .filter(|imported| imported.registered)
+        {
+            imported.registered = false;
+            registry::unregister(imported.ptr.as_ptr());
+        }
+    }
+
+    /// Write-protects the pages of this foreign region.
+    #[inline]
+    fn protect(self) -> Self {
+        #[cfg(feature = "protect")]
+        {
+            let mut this = self;
+            if let Some(imported) = this.imported.as_mut() {
+                imported.protected = protect::protect(imported.ptr.as_ptr(), imported.len_bytes);
+            }
+            this
+        }
+        #[cfg(not(feature = "protect"))]
+        self
+    }
+
+    /// Restores the protection of the pages of this foreign region, if they were
+    /// write-protected.
+    #[inline]
+    fn unprotect(&mut self) {
+        #[cfg(feature = "protect")]
+        if let Some(imported) = self.imported.as_mut() {
+            if let Some(original) = imported.protected.take() {
+                protect::unprotect(imported.ptr.as_ptr(), imported.len_bytes, original);
+            }
+        }
+    }
+
+    /// Consumes this buffer and returns its region as a slice that is never released,
+    /// as in [`Vec::leak`]. This is typically used to obtain a `&'static [T]`.
+    ///
+    /// The owner of a foreign region is forgotten, i.e. it is never dropped. Since the
+    /// region is only guaranteed to be valid while the owner is, the returned slice
+    /// cannot outlive `D`.
+    pub fn leak<'a>(self) -> &'a [T]
+    where
+        D: 'a,
+        T: 'a,
+    {
+        #[cfg(feature = "alloc")]
+        let this = match self.try_into_vec() {
+            Ok(vec) => return vec.leak(),
+            Err(this) => this,
+        };
+        // without `alloc`, native buffers are empty and own nothing
+        #[cfg(not(feature = "alloc"))]
+        let this = self;
+
+        // the owner is never dropped, and thus the region is never reported as released
+        let this = ManuallyDrop::new(this);
+        // Safety: the region is valid for as long as its owner, which is never dropped
+        unsafe { core::slice::from_raw_parts(this.ptr.as_ptr(), this.length) }
+    }
+
+    /// Removes the first `n` elements of this buffer.
+    ///
+    /// Foreign regions are not copied: this only moves the start of the view, and the whole
+    /// region remains pinned by its owner. Native regions shift the remaining elements,
+    /// as in [`Vec::drain`].
+    /// # Panics
+    /// This function panics if `n` is larger than the length of this buffer.
+    pub fn advance(&mut self, n: usize) {
+        assert!(
+            n <= self.length,
+            "cannot advance by {} elements a buffer of length {}",
+            n,
+            self.length
+        );
+        match self.mode() {
+            Allocation::Foreign(_) => {
+                // Safety: `n <= length`, so the new pointer is in bounds of the region
+                self.ptr = unsafe { self.ptr.add(n) };
+                self.length -= n;
+            }
+            Allocation::Native(_) => {
+                // without `alloc`, native buffers are empty and `n` is zero
+                #[cfg(feature = "alloc")]
+                self.get_vec().unwrap().drain(..n);
+            }
+        }
+    }
+
+    /// Shortens this buffer to `len` elements, as in [`Vec::truncate`].
+    /// This has no effect if `len` is larger than the length of this buffer.
+    ///
+    /// Foreign regions are not copied nor released: this only shortens the view.
+    pub fn truncate(&mut self, len: usize) {
+        match self.mode() {
+            Allocation::Foreign(_) => self.length = self.length.min(len),
+            Allocation::Native(_) => {
+                // without `alloc`, native buffers are empty
+                #[cfg(feature = "alloc")]
+                self.get_vec().unwrap().truncate(len)
+            }
+        }
+    }
+
+    /// Returns how the region was allocated, i.e. whether it is native or foreign.
+    #[inline]
+    pub fn allocation(&self) -> AllocationKind {
+        match self.mode() {
+            Allocation::Native(_) => AllocationKind::Native,
+            Allocation::Foreign(_) => AllocationKind::Foreign,
+        }
+    }
+
+    /// Returns whether the region was allocated by a foreign allocator.
+    #[inline]
+    pub fn is_foreign(&self) -> bool {
+        self.capacity == FOREIGN
+    }
+
+    /// Returns the number of elements this buffer can hold without reallocating, as in
+    /// [`Vec::capacity`]. Foreign regions cannot be resized in place and thus their
+    /// capacity is their length.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match self.mode() {
+            Allocation::Native(_) if size_of::<T>() == 0 => usize::MAX,
+            Allocation::Native(capacity) => capacity,
+            Allocation::Foreign(_) => self.length,
+        }
+    }
+
+    /// Returns whether both buffers are the same region, i.e. have the same starting pointer
+    /// and length, as in [`core::ptr::eq`] for slices. This does not compare the elements.
+    ///
+    /// This is useful to deduplicate regions imported more than once.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.as_ref(), other.as_ref())
+    }
+
+    /// Returns whether `ptr` points to an element of the region, i.e. whether it is in
+    /// [`as_ptr_range`](slice::as_ptr_range). This is always false for empty regions
+    /// (including those of zero-sized types).
+    #[inline]
+    pub fn contains_ptr(&self, ptr: *const T) -> bool {
+        self.as_ptr_range().contains(&ptr)
+    }
+
+    /// Returns the region as a mutable slice, regardless of how it was allocated.
+    ///
+    /// Foreign regions are treated as immutable by the safe API, since they may be
+    /// read-only or shared with the foreign side. This is useful when they are neither,
+    /// e.g. a region allocated by the foreign side for us to fill.
+    /// See [`ExclusiveOwner`] to do so safely for owners of such regions.
+    /// # Safety
+    /// This function is safe if and only if the region is writable and, for as long as the
+    /// returned slice lives, not referenced by anyone else (including the foreign side
+    /// and buffers sharing its owner, e.g. via [`ForeignVec::clone_shared`]).
+    #[inline]
+    pub unsafe fn as_mut_slice_unchecked(&mut self) -> &mut [T] {
+        self.unprotect();
+        core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.length)
+    }
+
+    /// Returns how the region was allocated, and its owner if foreign.
+    #[inline]
+    fn mode(&self) -> Allocation<&D> {
+        if self.capacity == FOREIGN {
+            // Safety: the owner is initialized for foreign regions
+            Allocation::Foreign(unsafe { self.owner.assume_init_ref() })
+        } else {
+            Allocation::Native(self.capacity)
+        }
+    }
+
+    /// Reinterprets this buffer as a buffer of `length` elements of `U`, keeping its owner
+    /// and the region recorded on import (i.e. it is not imported again).
+    /// # Safety
+    /// The region must be valid for `length` elements of `U` and, if native, the `Vec`
+    /// of its raw parts must be a valid `Vec<U>`.
+    #[inline]
+    pub(crate) unsafe fn cast_unchecked<U>(self, length: usize) -> ForeignVec<D, U> {
+        let this = ManuallyDrop::new(self);
+        ForeignVec {
+            ptr: this.ptr.cast(),
+            length,
+            capacity: this.capacity,
+            owner: core::ptr::read(&this.owner),
+            marker: PhantomData,
+            #[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+            imported: this.imported,
+        }
+    }
+
+    /// Returns the region and how it was allocated, without dropping either.
+    /// A foreign region is reported as released to [`hooks`].
+    #[cfg(feature = "alloc")]
+    fn into_parts(mut self) -> (NonNull<T>, usize, Allocation<D>) {
+        self.unprotect();
+        self.unregister();
+        self.untrack();
+        let this = ManuallyDrop::new(self);
+        let allocation = if this.capacity == FOREIGN {
+            // Safety: the owner is initialized for foreign regions, and `this` is
+            // neither used nor dropped afterwards
+            Allocation::Foreign(unsafe { this.owner.assume_init_read() })
+        } else {
+            Allocation::Native(this.capacity)
+        };
+        (this.ptr, this.length, allocation)
     }
 }
 
-impl<D, T> Drop for ForeignVec<D, T> {
+impl<D, T> ForeignVec<D, MaybeUninit<T>> {
+    /// Takes ownership of an allocated memory region that may not be initialized,
+    /// as in [`ForeignVec::from_foreign`].
+    ///
+    /// Once initialized (e.g. by the foreign side), use [`ForeignVec::assume_init`] to
+    /// access it as `[T]`. An uninitialized tail can be dropped with [`ForeignVec::truncate`].
+    /// # Panics
+    /// This function panics if and only if [`ForeignVec::try_from_foreign`] errors, e.g.
+    /// with the `registry` feature, when the region overlaps a live region
+    /// # Safety
+    /// This function is safe if and only if `ptr` is valid (but not necessarily initialized)
+    /// for `length`
+    /// # Implementation
+    /// This function leaks if and only if `owner` does not deallocate
+    /// the region `[ptr, ptr+length[` when dropped.
     #[inline]
-    fn drop(&mut self) {
-        match self.allocation {
+    pub unsafe fn from_foreign_uninit(ptr: *const T, length: usize, owner: D) -> Self
+    where
+        D: Owner,
+    {
+        // the region is expected to be written (e.g. by the foreign side), and is thus
+        // not write-protected
+        match Self::import(ptr as *const MaybeUninit<T>, length, owner)
+            .track()
+            .register()
+        {
+            Ok(vec) => vec,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Converts this buffer into a buffer of `T`, without copying it, as in
+    /// [`MaybeUninit::assume_init`].
+    ///
+    /// As in [`ForeignVec::from_foreign_any`], the elements of a foreign region are not
+    /// dropped by the returned buffer.
+    /// # Safety
+    /// This function is safe if and only if every element of this buffer is initialized.
+    #[inline]
+    pub unsafe fn assume_init(self) -> ForeignVec<D, T> {
+        // `MaybeUninit<T>` has the same layout as `T`
+        let length = self.length;
+        self.cast_unchecked(length)
+    }
+}
+
+impl<D: Owner> ForeignVec<D, u8> {
+    /// Takes ownership of an allocated, NUL-terminated string (e.g. a C string), as in
+    /// [`ForeignVec::from_foreign`]. The length is the number of bytes before the first NUL,
+    /// which is not part of the region.
+    ///
+    /// When `max_len` is `Some`, at most `max_len` bytes are scanned, and the region is
+    /// the first `max_len` bytes if none of them is NUL. A null `ptr` results in an empty region.
+    /// # Safety
+    /// This function is safe if and only if `ptr` is null or valid for reads up to and
+    /// including the first NUL or, when `max_len` is `Some`, up to `max_len` bytes, whichever
+    /// comes first.
+    /// # Implementation
+    /// This function leaks if and only if `owner` does not deallocate the string when dropped.
+    pub unsafe fn from_null_terminated(ptr: *const u8, max_len: Option<usize>, owner: D) -> Self {
+        let mut length = 0;
+        if !ptr.is_null() {
+            while max_len.is_none_or(|max_len| length < max_len) && *ptr.add(length) != 0 {
+                length += 1;
+            }
+        }
+        Self::from_foreign(ptr, length, owner)
+    }
+}
+
+/// Errors returned when importing a foreign region via [`ForeignVec::try_from_foreign`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportError {
+    /// The pointer is null and the length is not zero.
+    Null,
+    /// The pointer is not aligned to the element type.
+    Misaligned,
+    /// The region is larger than `isize::MAX` bytes.
+    TooLarge,
+    /// The region overlaps a live region, e.g. it was imported twice. Only detected with
+    /// the `registry` feature.
+    Overlapping,
+}
+
+/// Validates the region `[ptr, ptr+length[` as described in [`ForeignVec::try_from_foreign`],
+/// returning the pointer with which it is stored.
+pub(crate) fn validate_region<T>(ptr: *const T, length: usize) -> Result<NonNull<T>, ImportError> {
+    if size_of::<T>() == 0 || (ptr.is_null() && length == 0) {
+        Ok(NonNull::dangling())
+    } else if ptr.is_null() {
+        Err(ImportError::Null)
+    } else if !ptr.is_aligned() {
+        Err(ImportError::Misaligned)
+    } else if length > isize::MAX as usize / size_of::<T>() {
+        Err(ImportError::TooLarge)
+    } else {
+        // Safety: just checked
+        Ok(unsafe { NonNull::new_unchecked(ptr as *mut T) })
+    }
+}
+
+impl core::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Null => write!(f, "the pointer of a non-empty region must not be null"),
+            Self::Misaligned => write!(f, "the pointer must be aligned to the element type"),
+            Self::TooLarge => write!(f, "the region must not be larger than isize::MAX bytes"),
+            Self::Overlapping => write!(f, "the region must not overlap a live region"),
+        }
+    }
+}
+
+impl core::error::Error for ImportError {}
+
+/// Something that keeps a foreign region alive, and releases it when dropped (e.g. a
+/// handle to a buffer allocated by a foreign allocator).
+///
+/// This is a marker trait bounding the owners accepted by [`ForeignVec::from_foreign`], so
+/// that generic code can name them. Implementing it has no requirements besides the ones
+/// of [`ForeignVec::from_foreign`] itself.
+pub trait Owner {}
+
+impl Owner for () {}
+
+impl Owner for NoOwner {}
+
+/// A borrow keeps the region alive for as long as it lives.
+impl<T: ?Sized> Owner for &T {}
+
+/// A buffer keeps its region alive, e.g. when a region is a slice of another one.
+impl<D, T> Owner for ForeignVec<D, T> {}
+
+/// An owner that does not own anything, for regions that live for `'static`
+/// (see [`ForeignVec::from_static`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NoOwner;
+
+impl<T> ForeignVec<NoOwner, T> {
+    /// Returns a foreign [`ForeignVec`] over a `'static` slice, without copying it.
+    #[inline]
+    pub fn from_static(data: &'static [T]) -> Self {
+        // Safety: the region is valid for `'static`
+        unsafe { Self::import(data.as_ptr(), data.len(), NoOwner) }
+    }
+}
+
+/// An owner whose clones keep the same region alive, e.g. an [`Arc`].
+///
+/// This allows buffers owned by it to be cloned without copying the region via
+/// [`ForeignVec::clone_shared`].
+/// # Safety
+/// Implementors must guarantee that the region kept alive by an owner is kept alive
+/// by each of its clones. For example, `Vec<T>` must not implement this trait, since
+/// its clones own new allocations.
+pub unsafe trait SharedOwner: Owner + Clone {}
+
+// Safety: `NoOwner` is only used for `'static` regions
+unsafe impl SharedOwner for NoOwner {}
+
+impl<D: SharedOwner, T: Clone> ForeignVec<D, T> {
+    /// Returns a clone of this buffer that, when it is foreign, shares the region with `self`
+    /// by cloning its owner (e.g. incrementing the reference count of an [`Arc`]).
+    /// Native buffers are cloned as in [`Clone`].
+    pub fn clone_shared(&self) -> Self {
+        self.share(self)
+    }
+
+    /// Returns an iterator over chunks of `chunk_len` elements of this buffer (the last
+    /// chunk may be shorter), as in [`slice::chunks`], where each chunk is a [`ForeignVec`].
+    ///
+    /// Chunks of foreign buffers share the region with `self` as in
+    /// [`ForeignVec::clone_shared`], and can thus outlive `self`. Chunks of native
+    /// buffers are cloned.
+    /// # Panics
+    /// This function panics if `chunk_len` is zero.
+    pub fn chunks_shared(&self, chunk_len: usize) -> ChunksShared<'_, D, T> {
+        ChunksShared::new(self, chunk_len)
+    }
+
+    /// Returns a buffer with the elements of `slice`, a subslice of `self`, that shares
+    /// the region with `self` if it is foreign.
+    pub(crate) fn share(&self, slice: &[T]) -> Self {
+        match self.mode() {
+            // Safety: the region is valid for as long as any clone of its owner is alive,
+            // and `slice` is in bounds of it
+            Allocation::Foreign(owner) => unsafe {
+                self.import_shared(slice.as_ptr(), slice.len(), owner.clone())
+            },
+            #[cfg(feature = "alloc")]
+            Allocation::Native(_) => slice.to_vec().into(),
+            // without `alloc`, native buffers are empty
+            #[cfg(not(feature = "alloc"))]
+            Allocation::Native(_) => Self::new(),
+        }
+    }
+}
+
+impl<D: SharedOwner, T> ForeignVec<D, T> {
+    /// Splits this buffer in two at `at`, as in [`Vec::split_off`]: `self` keeps the
+    /// elements `[0, at[` and the elements `[at, len[` are returned.
+    ///
+    /// Foreign regions are not copied: both buffers share the region via a clone of
+    /// its owner. Native regions copy the returned elements into a new allocation.
+    /// # Panics
+    /// This function panics if `at` is larger than the length of this buffer.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(
+            at <= self.length,
+            "cannot split off at {} a buffer of length {}",
+            at,
+            self.length
+        );
+        match self.mode() {
+            Allocation::Foreign(owner) => {
+                let owner = owner.clone();
+                // Safety: the region is valid for as long as any clone of its owner is alive,
+                // and `at <= length`
+                let other = unsafe {
+                    self.import_shared(self.ptr.as_ptr().add(at), self.length - at, owner)
+                };
+                self.length = at;
+                other
+            }
+            #[cfg(feature = "alloc")]
+            Allocation::Native(_) => self.get_vec().unwrap().split_off(at).into(),
+            // without `alloc`, native buffers are empty
+            #[cfg(not(feature = "alloc"))]
+            Allocation::Native(_) => Self::new(),
+        }
+    }
+}
+
+/// An owner whose regions are uniquely owned and writable, e.g. a handle to an allocation
+/// made by the foreign side for us to fill.
+///
+/// This allows buffers owned by it to be mutated in place via [`ForeignVec::as_mut_slice`],
+/// without `unsafe`.
+/// # Safety
+/// Implementors must guarantee that every region imported with this owner is writable
+/// and, for as long as the owner is alive, not referenced by anyone else (including
+/// the foreign side). For example, an owner implementing [`SharedOwner`] must not
+/// implement this trait, since buffers sharing its region would alias it.
+pub unsafe trait ExclusiveOwner: Owner {}
+
+impl<D: ExclusiveOwner, T> ForeignVec<D, T> {
+    /// Returns the region as a mutable slice, regardless of how it was allocated.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: native regions are uniquely owned by `self`, and so are foreign ones by
+        // the contract of `ExclusiveOwner`
+        unsafe { self.as_mut_slice_unchecked() }
+    }
+}
+
+impl<D, T> ForeignVec<D, T> {
+    /// Releases the region: drops the owner of a foreign region, or the elements and the
+    /// allocation of a native region. This only accesses values of `T` to drop them.
+    #[inline]
+    fn release(&mut self) {
+        match self.mode() {
             Allocation::Foreign(_) => {
+                // the owner may write to the region when releasing it
+                self.unprotect();
+                // the region may be imported again as soon as it is released
+                self.unregister();
                 // the foreign is dropped via its `Drop`
+                // Safety: the owner is initialized for foreign regions
+                unsafe { self.owner.assume_init_drop() };
+                self.untrack();
             }
-            Allocation::Native => {
-                let data = core::mem::take(&mut self.data);
-                let _ = ManuallyDrop::into_inner(data);
+            #[cfg(feature = "alloc")]
+            Allocation::Native(capacity) => {
+                // Safety: a native region is the raw parts of a `Vec`
+                let _ = unsafe { native_vec(self.ptr, self.length, capacity) };
             }
+            // without `alloc`, native buffers are empty and own nothing
+            #[cfg(not(feature = "alloc"))]
+            Allocation::Native(_) => {}
         }
     }
 }
 
+#[cfg(not(foreign_vec_nightly))]
+impl<D, T> Drop for ForeignVec<D, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.release()
+    }
+}
+
+// Safety: `release` only drops values of `T` (as `Vec<T>` does), which `marker` declares
+#[cfg(foreign_vec_nightly)]
+unsafe impl<D, #[may_dangle] T> Drop for ForeignVec<D, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.release()
+    }
+}
+
 impl<D, T> core::ops::Deref for ForeignVec<D, T> {
     type Target = [T];
 
     #[inline]
     fn deref(&self) -> &[T] {
-        &self.data
+        // Safety: `ptr` is valid for `length` in both modes
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.length) }
     }
 }
 
-impl<D, T: core::fmt::Debug> core::fmt::Debug for ForeignVec<D, T> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        core::fmt::Debug::fmt(&**self, f)
+impl<D, T, I: SliceIndex<[T]>> Index<I> for ForeignVec<D, T> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        Index::index(&**self, index)
     }
 }
 
-impl<D, T> From<Vec<T>> for ForeignVec<D, T> {
+impl<D, T> AsRef<[T]> for ForeignVec<D, T> {
     #[inline]
-    fn from(data: Vec<T>) -> Self {
-        Self {
-            data: ManuallyDrop::new(data),
-            allocation: Allocation::Native,
-        }
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+/// [`ForeignVec`] compares and hashes as `[T]` does, so that it can be looked up by slice
+/// when used as the key of a map.
+impl<D, T> core::borrow::Borrow<[T]> for ForeignVec<D, T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+// Safety: the region never moves: it is either owned by a `Vec` (which is itself `StableDeref`)
+// or by the foreign owner `D`, which is never exposed mutably.
+#[cfg(feature = "stable_deref_trait")]
+unsafe impl<D, T> stable_deref_trait::StableDeref for ForeignVec<D, T> {}
+
+impl<D, T> Default for ForeignVec<D, T> {
+    /// Returns an empty, native [`ForeignVec`]. This does not allocate.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -0,0 +1,264 @@
+//! Contains [`ForeignMatrix`], a strided two-dimensional view over a region.
+use crate::{ForeignVec, SharedOwner};
+
+/// Errors returned when the shape of a [`ForeignMatrix`] is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The row stride is smaller than the number of columns, i.e. rows would overlap.
+    StrideTooSmall {
+        /// The row stride
+        row_stride: usize,
+        /// The number of columns
+        cols: usize,
+    },
+    /// The last row is not in bounds of the values.
+    OutOfBounds {
+        /// The number of values required by the shape, or `None` if it overflows `usize`
+        required: Option<usize>,
+        /// The number of values
+        values: usize,
+    },
+}
+
+impl core::fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StrideTooSmall { row_stride, cols } => write!(
+                f,
+                "row stride ({row_stride}) is smaller than the number of columns ({cols})"
+            ),
+            Self::OutOfBounds {
+                required: Some(required),
+                values,
+            } => write!(
+                f,
+                "the shape requires {required} values but there are {values}"
+            ),
+            Self::OutOfBounds {
+                required: None,
+                values,
+            } => write!(
+                f,
+                "the shape requires more than usize::MAX values but there are {values}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for MatrixError {}
+
+/// Checks that rows do not overlap and that the last row is in bounds of `values_len`.
+fn validate(
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    values_len: usize,
+) -> Result<(), MatrixError> {
+    if row_stride < cols {
+        return Err(MatrixError::StrideTooSmall { row_stride, cols });
+    }
+    if rows == 0 {
+        return Ok(());
+    }
+    let required = (rows - 1)
+        .checked_mul(row_stride)
+        .and_then(|start| start.checked_add(cols));
+    match required {
+        Some(required) if required <= values_len => Ok(()),
+        _ => Err(MatrixError::OutOfBounds {
+            required,
+            values: values_len,
+        }),
+    }
+}
+
+/// An immutable, row-major, strided two-dimensional view over values that may be foreign,
+/// e.g. a tensor or a block of a larger matrix.
+///
+/// Row `i` is `values[i * row_stride..i * row_stride + cols]`; the values between rows
+/// (if `row_stride > cols`) are not part of the matrix. Column-major blocks can be viewed
+/// as their transpose. The shape is validated on construction, so that accessing any row
+/// is always in bounds.
+pub struct ForeignMatrix<D, T> {
+    values: ForeignVec<D, T>,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+}
+
+impl<D, T> ForeignMatrix<D, T> {
+    /// Creates a new [`ForeignMatrix`] of `rows` rows of `cols` values each, that are
+    /// `row_stride` values apart in `values`.
+    /// # Errors
+    /// This function errors iff `row_stride < cols` or the last row is not in bounds
+    /// of `values`.
+    pub fn try_new(
+        values: ForeignVec<D, T>,
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+    ) -> Result<Self, MatrixError> {
+        validate(rows, cols, row_stride, values.len())?;
+        Ok(Self {
+            values,
+            rows,
+            cols,
+            row_stride,
+        })
+    }
+
+    /// Creates a new [`ForeignMatrix`] from its values and shape without validating them.
+    /// # Safety
+    /// This function is safe iff [`ForeignMatrix::try_new`] would succeed.
+    #[inline]
+    pub unsafe fn new_unchecked(
+        values: ForeignVec<D, T>,
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+    ) -> Self {
+        Self {
+            values,
+            rows,
+            cols,
+            row_stride,
+        }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of values between the start of two consecutive rows.
+    #[inline]
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    /// Returns row `i`.
+    /// # Panics
+    /// This function panics iff `i >= self.rows()`.
+    #[inline]
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.rows, "index out of bounds");
+        // Safety: just checked
+        unsafe { self.row_unchecked(i) }
+    }
+
+    /// Returns row `i`, or `None` if `i` is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&[T]> {
+        // Safety: just checked
+        (i < self.rows).then(|| unsafe { self.row_unchecked(i) })
+    }
+
+    /// Returns row `i` without bounds checking.
+    /// # Safety
+    /// This function is safe iff `i < self.rows()`.
+    #[inline]
+    pub unsafe fn row_unchecked(&self, i: usize) -> &[T] {
+        let start = i * self.row_stride;
+        // rows are in bounds by construction
+        self.values.get_unchecked(start..start + self.cols)
+    }
+
+    /// Returns an iterator over the rows of this matrix.
+    #[inline]
+    pub fn iter_rows(&self) -> Rows<'_, D, T> {
+        Rows {
+            matrix: self,
+            index: 0,
+            end: self.rows,
+        }
+    }
+
+    /// Returns the values of this matrix, including the values between rows.
+    #[inline]
+    pub fn values(&self) -> &ForeignVec<D, T> {
+        &self.values
+    }
+
+    /// Returns the values of this matrix.
+    #[inline]
+    pub fn into_inner(self) -> ForeignVec<D, T> {
+        self.values
+    }
+}
+
+impl<D: SharedOwner, T: Clone> ForeignMatrix<D, T> {
+    /// Returns row `i` as a buffer that, when the values are foreign, shares the region
+    /// with this matrix by cloning its owner, as in [`ForeignVec::chunks_shared`].
+    /// Native rows are copied.
+    /// # Panics
+    /// This function panics iff `i >= self.rows()`.
+    #[inline]
+    pub fn row_shared(&self, i: usize) -> ForeignVec<D, T> {
+        self.values.share(self.row(i))
+    }
+}
+
+impl<D, T: core::fmt::Debug> core::fmt::Debug for ForeignMatrix<D, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
+
+impl<'a, D, T> IntoIterator for &'a ForeignMatrix<D, T> {
+    type Item = &'a [T];
+    type IntoIter = Rows<'a, D, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_rows()
+    }
+}
+
+/// An iterator over the rows of a [`ForeignMatrix`].
+pub struct Rows<'a, D, T> {
+    matrix: &'a ForeignMatrix<D, T>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, D, T> Iterator for Rows<'a, D, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.index == self.end {
+            return None;
+        }
+        // Safety: `index < end <= rows`
+        let row = unsafe { self.matrix.row_unchecked(self.index) };
+        self.index += 1;
+        Some(row)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D, T> DoubleEndedIterator for Rows<'a, D, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.index == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // Safety: `end < rows`
+        Some(unsafe { self.matrix.row_unchecked(self.end) })
+    }
+}
+
+impl<'a, D, T> ExactSizeIterator for Rows<'a, D, T> {}
@@ -0,0 +1,219 @@
+//! Native regions, i.e. regions allocated by [`Vec`].
+use core::marker::PhantomData;
+use core::mem::{size_of, ManuallyDrop, MaybeUninit};
+use core::ptr::NonNull;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::{Allocation, ForeignVec, NoOwner, Owner, SharedOwner, VecMut};
+
+/// Returns the capacity with which `vec` is stored in a [`ForeignVec`].
+#[inline]
+pub(crate) fn native_capacity<T>(vec: &Vec<T>) -> usize {
+    if size_of::<T>() == 0 {
+        // `Vec` reports `usize::MAX`, which would collide with `FOREIGN`
+        0
+    } else {
+        vec.capacity()
+    }
+}
+
+/// Rebuilds the `Vec` of a native region from the fields of a [`ForeignVec`].
+/// # Safety
+/// The fields must be of a native region
+#[inline]
+pub(crate) unsafe fn native_vec<T>(ptr: NonNull<T>, length: usize, capacity: usize) -> Vec<T> {
+    // the inverse of `native_capacity`
+    let capacity = if size_of::<T>() == 0 {
+        usize::MAX
+    } else {
+        capacity
+    };
+    Vec::from_raw_parts(ptr.as_ptr(), length, capacity)
+}
+
+impl<D, T> ForeignVec<D, T> {
+    /// Returns a `Some` mutable reference of [`Vec<T>`] iff this was initialized
+    /// from a [`Vec<T>`] and `None` otherwise.
+    pub fn get_vec(&mut self) -> Option<VecMut<'_, D, T>> {
+        match self.mode() {
+            Allocation::Foreign(_) => None,
+            // Safety: the region is native
+            Allocation::Native(capacity) => Some(unsafe { VecMut::new(self, capacity) }),
+        }
+    }
+
+    /// Returns a mutable reference of [`Vec<T>`], first copying the region into a
+    /// new [`Vec<T>`] (and releasing its owner) if it is foreign.
+    pub fn make_mut(&mut self) -> VecMut<'_, D, T>
+    where
+        T: Clone,
+    {
+        if let Allocation::Foreign(_) = self.mode() {
+            *self = self.to_vec().into();
+        }
+        match self.mode() {
+            // Safety: the region is native
+            Allocation::Native(capacity) => unsafe { VecMut::new(self, capacity) },
+            Allocation::Foreign(_) => unreachable!(),
+        }
+    }
+
+    /// Returns a native [`ForeignVec`] with the same elements, copying the region into a
+    /// new [`Vec<T>`] if it is foreign. The owner of a foreign region is dropped (and thus
+    /// the region released) before this function returns.
+    ///
+    /// Native regions are returned as is.
+    pub fn into_native(self) -> Self
+    where
+        T: Clone,
+    {
+        match self.try_into_vec() {
+            Ok(vec) => vec.into(),
+            Err(this) => this.to_vec().into(),
+        }
+    }
+
+    /// Returns a native [`ForeignVec`] with the elements of all `parts`, in order,
+    /// allocating once.
+    pub fn concat(parts: &[ForeignVec<D, T>]) -> Self
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(parts.iter().map(|part| part.len()).sum());
+        for part in parts {
+            vec.extend_from_slice(part);
+        }
+        vec.into()
+    }
+
+    /// Moves the elements of `other` to the end of this buffer, leaving `other` empty,
+    /// as in [`Vec::append`].
+    ///
+    /// This buffer is made native first (see [`ForeignVec::make_mut`]). The elements of
+    /// a foreign `other` are cloned, and its owner is released.
+    pub fn append<E>(&mut self, other: &mut ForeignVec<E, T>)
+    where
+        T: Clone,
+    {
+        let mut vec = self.make_mut();
+        if other.is_foreign() {
+            vec.extend_from_slice(other);
+            *other = ForeignVec::new();
+        } else {
+            vec.append(&mut other.get_vec().unwrap());
+        }
+    }
+
+    /// Returns the [`Vec<T>`] iff this was initialized from a [`Vec<T>`] and `self` otherwise.
+    pub(crate) fn try_into_vec(self) -> Result<Vec<T>, Self> {
+        match self.mode() {
+            Allocation::Foreign(_) => Err(self),
+            Allocation::Native(capacity) => {
+                let (ptr, length, _) = self.into_parts();
+                // Safety: a native region is the raw parts of a `Vec`
+                Ok(unsafe { native_vec(ptr, length, capacity) })
+            }
+        }
+    }
+}
+
+impl<T> Owner for Vec<T> {}
+
+impl<T: ?Sized> Owner for Box<T> {}
+
+impl<T: ?Sized> Owner for Arc<T> {}
+
+impl<T: ?Sized> Owner for Rc<T> {}
+
+// Safety: clones of an `Arc` point to the same allocation
+unsafe impl<T: ?Sized> SharedOwner for Arc<T> {}
+
+// Safety: clones of an `Rc` point to the same allocation
+unsafe impl<T: ?Sized> SharedOwner for Rc<T> {}
+
+/// Clones the region into a native allocation, regardless of how it was allocated.
+/// See [`ForeignVec::clone_shared`] for a clone that shares foreign regions.
+impl<D, T: Clone> Clone for ForeignVec<D, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.to_vec().into()
+    }
+}
+
+impl<D, T> From<Vec<T>> for ForeignVec<D, T> {
+    #[inline]
+    fn from(data: Vec<T>) -> Self {
+        let mut data = ManuallyDrop::new(data);
+        Self {
+            // Safety: the pointer of a `Vec` is never null
+            ptr: unsafe { NonNull::new_unchecked(data.as_mut_ptr()) },
+            length: data.len(),
+            capacity: native_capacity(&data),
+            owner: MaybeUninit::uninit(),
+            marker: PhantomData,
+            #[cfg(any(feature = "hooks", feature = "protect", feature = "registry"))]
+            imported: None,
+        }
+    }
+}
+
+impl<D, T> From<Box<[T]>> for ForeignVec<D, T> {
+    /// Converts a boxed slice into a native [`ForeignVec`] without copying it.
+    #[inline]
+    fn from(data: Box<[T]>) -> Self {
+        data.into_vec().into()
+    }
+}
+
+impl<T: Clone> From<Cow<'static, [T]>> for ForeignVec<NoOwner, T> {
+    /// Converts an owned `Cow` into a native [`ForeignVec`] and a borrowed one into a
+    /// foreign [`ForeignVec`] over the `'static` slice, both without copying it.
+    #[inline]
+    fn from(data: Cow<'static, [T]>) -> Self {
+        match data {
+            Cow::Owned(data) => data.into(),
+            Cow::Borrowed(data) => Self::from_static(data),
+        }
+    }
+}
+
+impl<T> From<Arc<[T]>> for ForeignVec<Arc<[T]>, T> {
+    /// Converts an [`Arc<[T]>`] into a foreign [`ForeignVec`] owned by the [`Arc`] itself,
+    /// sharing the region instead of copying it.
+    #[inline]
+    fn from(data: Arc<[T]>) -> Self {
+        // Safety: the region is valid for as long as the `Arc` is alive
+        unsafe { Self::import(data.as_ptr(), data.len(), data) }
+    }
+}
+
+impl<D, T> FromIterator<T> for ForeignVec<D, T> {
+    /// Collects into a native [`ForeignVec`].
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vec::from_iter(iter).into()
+    }
+}
+
+impl<D, T: Clone> Extend<T> for ForeignVec<D, T> {
+    /// Extends the region, first copying it into a native allocation if it is foreign
+    /// (see [`ForeignVec::make_mut`]).
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.make_mut().extend(iter)
+    }
+}
+
+impl<'a, D, T: Copy + 'a> Extend<&'a T> for ForeignVec<D, T> {
+    /// Extends the region, first copying it into a native allocation if it is foreign
+    /// (see [`ForeignVec::make_mut`]).
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.make_mut().extend(iter)
+    }
+}
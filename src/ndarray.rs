@@ -0,0 +1,34 @@
+use ::ndarray::{Array1, ArrayView1, CowArray, Ix1};
+
+use crate::ForeignVec;
+
+impl<D, T> ForeignVec<D, T> {
+    /// Returns a view of this region as a one-dimensional array, without copying it.
+    #[inline]
+    pub fn as_array_view(&self) -> ArrayView1<'_, T> {
+        ArrayView1::from(&**self)
+    }
+
+    /// Returns a (borrowed) [`CowArray`] of this region, without copying it.
+    #[inline]
+    pub fn as_cow_array(&self) -> CowArray<'_, T, Ix1> {
+        self.as_array_view().into()
+    }
+}
+
+impl<D, T> From<Array1<T>> for ForeignVec<D, T> {
+    /// Converts an array into a native [`ForeignVec`], without copying it if it is in standard
+    /// layout and starts at the start of its allocation. Arrays in standard layout are moved
+    /// within their allocation otherwise, and other arrays are collected into a new one.
+    fn from(array: Array1<T>) -> Self {
+        if !array.is_standard_layout() {
+            return array.into_iter().collect();
+        }
+        let len = array.len();
+        let (mut vec, offset) = array.into_raw_vec_and_offset();
+        let offset = offset.unwrap_or(0);
+        vec.truncate(offset + len);
+        vec.drain(..offset);
+        vec.into()
+    }
+}
@@ -0,0 +1,80 @@
+use alloc::sync::Arc;
+
+use crate::{ForeignVec, HeapSize, Owner, SharedOwner};
+
+/// A reference-counted owner from which many [`ForeignVec`]s can be imported, e.g.
+/// over the different regions of a single foreign allocation.
+///
+/// The owner `D` is dropped (and thus its regions released) when the last
+/// [`OwnerHandle`] (including those owned by [`ForeignVec`]s) is dropped.
+/// ```
+/// use foreign_vec::OwnerHandle;
+///
+/// // e.g. an array whose release callback releases both its offsets and values
+/// let (offsets, values) = (vec![0i32, 2, 5], b"hello".to_vec());
+/// let handle = OwnerHandle::new((offsets, values));
+/// let (offsets, values) = handle.get();
+/// let offsets = unsafe { handle.import(offsets.as_ptr(), offsets.len()) };
+/// let values = unsafe { handle.import(values.as_ptr(), values.len()) };
+/// drop(handle);
+/// assert_eq!(offsets, [0, 2, 5]);
+/// assert_eq!(values, b"hello");
+/// ```
+pub struct OwnerHandle<D> {
+    owner: Arc<D>,
+}
+
+impl<D> OwnerHandle<D> {
+    /// Returns a new [`OwnerHandle`] of `owner`.
+    #[inline]
+    pub fn new(owner: D) -> Self {
+        Self {
+            owner: Arc::new(owner),
+        }
+    }
+
+    /// Returns a reference to the owner.
+    #[inline]
+    pub fn get(&self) -> &D {
+        &self.owner
+    }
+
+    /// Takes ownership of an allocated memory region kept alive by the owner, as in
+    /// [`ForeignVec::from_foreign`], sharing the owner with this handle.
+    /// # Panics
+    /// This function panics if and only if [`ForeignVec::try_from_foreign`] errors
+    /// # Safety
+    /// This function is safe if and only if `ptr` is valid for `length` for as long as the
+    /// owner is alive.
+    #[inline]
+    pub unsafe fn import<T: Copy>(&self, ptr: *const T, length: usize) -> ForeignVec<Self, T> {
+        ForeignVec::from_foreign(ptr, length, self.clone())
+    }
+}
+
+impl<D> Clone for OwnerHandle<D> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            owner: self.owner.clone(),
+        }
+    }
+}
+
+impl<D> Owner for OwnerHandle<D> {}
+
+// Safety: clones of an `Arc` point to the same owner
+unsafe impl<D> SharedOwner for OwnerHandle<D> {}
+
+impl<D: HeapSize> HeapSize for OwnerHandle<D> {
+    #[inline]
+    fn heap_size(&self) -> usize {
+        self.owner.heap_size()
+    }
+}
+
+impl<D: core::fmt::Debug> core::fmt::Debug for OwnerHandle<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OwnerHandle").field(&self.owner).finish()
+    }
+}
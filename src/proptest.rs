@@ -0,0 +1,40 @@
+//! Contains strategies generating [`ForeignVec`]s for `proptest`.
+use ::proptest::collection::{vec, SizeRange};
+use ::proptest::prelude::{any, Strategy};
+
+use crate::testing::{MockOwner, Tracker};
+use crate::ForeignVec;
+
+/// Returns a strategy generating native and foreign [`ForeignVec`]s whose elements are
+/// generated by `element` and whose length is in `size`.
+///
+/// Foreign buffers own a copy of their elements via a [`MockOwner`] of a new [`Tracker`].
+/// Values shrink towards shorter, native buffers.
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::test_runner::TestRunner;
+/// use foreign_vec::proptest::foreign_vec;
+///
+/// TestRunner::default()
+///     .run(&foreign_vec(0..10i32, 0..100), |vec| {
+///         prop_assert!(vec.iter().all(|x| (0..10).contains(x)));
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+pub fn foreign_vec<S>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ForeignVec<MockOwner, S::Value>>
+where
+    S: Strategy,
+    S::Value: Clone + Send + Sync + 'static,
+{
+    (any::<bool>(), vec(element, size)).prop_map(|(foreign, data)| {
+        if foreign {
+            Tracker::new().import(&data)
+        } else {
+            data.into()
+        }
+    })
+}
@@ -0,0 +1,117 @@
+//! Write-protection of foreign regions, to catch writes through aliasing pointers.
+//!
+//! The pages fully contained in a region imported via [`ForeignVec::from_foreign`] are made
+//! read-only (via `mprotect`) until the [`ForeignVec`] stops owning it, at which point their
+//! original protection is restored, before the owner is dropped.
+//!
+//! Notes:
+//! * Regions whose start is not page-aligned are not protected.
+//! * The original protection is read from `/proc/self/maps`. Pages that are not writable
+//!   (e.g. read-only mappings and `.rodata`), or whose protection is not uniform, are not
+//!   protected.
+//! * Failing to protect or to restore pages (e.g. because they were unmapped) is ignored,
+//!   since this is only meant for debugging; in particular, releasing a region never panics.
+//! * Buffers sharing a region via a clone of its owner (e.g. [`ForeignVec::clone_shared`])
+//!   find its pages read-only and thus do not protect them: only the buffer that imported
+//!   the region restores them, when it is dropped, even if the buffers sharing it outlive it.
+//! * A leaked or forgotten buffer leaves its pages read-only.
+#[cfg(doc)]
+use crate::ForeignVec;
+
+/// The original protection (`PROT_*` flags) of write-protected pages.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+pub(crate) struct Protection(i32);
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn page_size() -> usize {
+    // Safety: `sysconf` has no preconditions
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+/// Returns the pages fully contained in `[ptr, ptr + len_bytes[`, as `(ptr, len_bytes)`,
+/// if any. Regions whose start is not page-aligned have none.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn pages(ptr: *const u8, len_bytes: usize) -> Option<(*const u8, usize)> {
+    let page_size = page_size();
+    let len_bytes = len_bytes - len_bytes % page_size;
+    ((ptr as usize).is_multiple_of(page_size) && len_bytes > 0).then_some((ptr, len_bytes))
+}
+
+/// Returns the protection of the mappings covering `[start, end[`, as in `/proc/self/maps`,
+/// or `None` if they are not all mapped with the same protection.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn protection(start: usize, end: usize) -> Option<i32> {
+    let maps = std::fs::read_to_string("/proc/self/maps").ok()?;
+    // the mappings are ordered by address; `covered` is the end of the covered prefix
+    let mut covered = start;
+    let mut protection = None;
+    for line in maps.lines() {
+        let mut fields = line.split(' ');
+        let (map_start, map_end) = fields.next()?.split_once('-')?;
+        let map_start = usize::from_str_radix(map_start, 16).ok()?;
+        let map_end = usize::from_str_radix(map_end, 16).ok()?;
+        if map_end <= covered {
+            continue;
+        }
+        if map_start > covered {
+            // a gap in the region
+            return None;
+        }
+        let flags = fields.next()?.as_bytes();
+        let map_protection = [
+            (b'r', libc::PROT_READ),
+            (b'w', libc::PROT_WRITE),
+            (b'x', libc::PROT_EXEC),
+        ]
+        .into_iter()
+        .zip(flags)
+        .filter(|((flag, _), actual)| flag == *actual)
+        .fold(libc::PROT_NONE, |protection, ((_, prot), _)| {
+            protection | prot
+        });
+        if *protection.get_or_insert(map_protection) != map_protection {
+            return None;
+        }
+        covered = map_end;
+        if covered >= end {
+            return protection;
+        }
+    }
+    None
+}
+
+/// Makes the pages fully contained in `[ptr, ptr + len_bytes[` read-only if they are
+/// writable, returning their original protection if they were protected.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn protect(ptr: *const u8, len_bytes: usize) -> Option<Protection> {
+    let (ptr, len_bytes) = pages(ptr, len_bytes)?;
+    let original = protection(ptr as usize, ptr as usize + len_bytes)?;
+    if original & libc::PROT_WRITE == 0 {
+        return None;
+    }
+    let protection = original & !libc::PROT_WRITE;
+    // Safety: the pages are in bounds of the region, which is valid while protected
+    let result = unsafe { libc::mprotect(ptr as *mut libc::c_void, len_bytes, protection) };
+    (result == 0).then_some(Protection(original))
+}
+
+/// Restores the original protection of the pages protected by [`protect`] for the same
+/// region. Failures are ignored: there is nothing to restore if the pages were unmapped.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn unprotect(ptr: *const u8, len_bytes: usize, original: Protection) {
+    if let Some((ptr, len_bytes)) = pages(ptr, len_bytes) {
+        // Safety: the pages were protected by `protect` and are still valid
+        unsafe { libc::mprotect(ptr as *mut libc::c_void, len_bytes, original.0) };
+    }
+}
+
+/// Write-protection is only supported on Linux, where the original protection of pages
+/// can be read.
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub(crate) fn protect(_: *const u8, _: usize) -> Option<Protection> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub(crate) fn unprotect(_: *const u8, _: usize, _: Protection) {}
@@ -0,0 +1,21 @@
+use ::rayon::iter::IntoParallelIterator;
+use ::rayon::slice::{Iter, ParallelSlice};
+
+use crate::ForeignVec;
+
+impl<'data, D, T: Sync + 'data> IntoParallelIterator for &'data ForeignVec<D, T> {
+    type Item = &'data T;
+    type Iter = Iter<'data, T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        <&[T]>::into_par_iter(self)
+    }
+}
+
+impl<D, T: Sync> ParallelSlice<T> for ForeignVec<D, T> {
+    #[inline]
+    fn as_parallel_slice(&self) -> &[T] {
+        self
+    }
+}
@@ -0,0 +1,57 @@
+//! A global registry of live foreign regions, to detect regions imported more than once.
+//!
+//! Every non-empty region imported via [`ForeignVec::from_foreign`] (or
+//! [`ForeignVec::try_from_foreign`] and [`ForeignVec::from_foreign_uninit`]) is recorded
+//! until the [`ForeignVec`] that imported it stops owning it. Importing a region that
+//! overlaps a live one fails, since both owners would release it (e.g. a double free):
+//! [`ForeignVec::try_from_foreign`] returns [`ImportError::Overlapping`], and
+//! [`ForeignVec::from_foreign`] panics. The owner of the region being imported is leaked,
+//! so that it does not release the region while the other buffer still uses it.
+//!
+//! Notes:
+//! * Buffers sharing a region via a clone of its owner (e.g. [`ForeignVec::clone_shared`])
+//!   are not recorded, since sharing a region is not an error.
+//! * Regions that are not allocated externally (e.g. [`ForeignVec::from_static`] and
+//!   `From<Arc<[T]>>`) are not recorded.
+//! * A buffer reinterpreted as another type (e.g. via `ForeignVec::try_cast`) keeps the
+//!   region recorded as it was imported.
+//! * A leaked region (see [`ForeignVec::leak`]) remains recorded.
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+#[cfg(doc)]
+use crate::{ForeignVec, ImportError};
+
+/// The live regions, as `start => end` addresses.
+static REGIONS: Mutex<BTreeMap<usize, usize>> = Mutex::new(BTreeMap::new());
+
+/// Returns the live regions, as `(ptr, len_bytes)`, ordered by address.
+pub fn live_regions() -> Vec<(*const u8, usize)> {
+    let regions = REGIONS.lock().unwrap_or_else(|e| e.into_inner());
+    regions
+        .iter()
+        .map(|(start, end)| (*start as *const u8, end - start))
+        .collect()
+}
+
+/// Records the region `[ptr, ptr + len_bytes[`, returning whether it was recorded, i.e.
+/// whether it does not overlap a live region.
+pub(crate) fn register(ptr: *const u8, len_bytes: usize) -> bool {
+    let (start, end) = (ptr as usize, ptr as usize + len_bytes);
+    let mut regions = REGIONS.lock().unwrap_or_else(|e| e.into_inner());
+    // the only region that may overlap is the last one starting before `end`
+    if let Some((_, &other_end)) = regions.range(..end).next_back() {
+        if other_end > start {
+            return false;
+        }
+    }
+    regions.insert(start, end);
+    true
+}
+
+/// Removes a region recorded by [`register`].
+pub(crate) fn unregister(ptr: *const u8) {
+    let mut regions = REGIONS.lock().unwrap_or_else(|e| e.into_inner());
+    regions.remove(&(ptr as usize));
+}
@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+
+use ::rkyv::rancor::Fallible;
+use ::rkyv::ser::{Allocator, Writer};
+use ::rkyv::vec::{ArchivedVec, VecResolver};
+use ::rkyv::{Archive, Deserialize, Place, Serialize};
+
+use crate::{ForeignVec, Owner};
+
+/// Archives as an [`ArchivedVec`], regardless of how the region was allocated.
+impl<D, T: Archive> Archive for ForeignVec<D, T> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self, resolver, out);
+    }
+}
+
+impl<D, T, S> Serialize<S> for ForeignVec<D, T>
+where
+    T: Serialize<S>,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_slice(self, serializer)
+    }
+}
+
+/// Deserializes into a native allocation.
+impl<D, T, De> Deserialize<ForeignVec<D, T>, De> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    ArchivedVec<T::Archived>: Deserialize<Vec<T>, De>,
+    De: Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, deserializer: &mut De) -> Result<ForeignVec<D, T>, De::Error> {
+        Deserialize::<Vec<T>, De>::deserialize(self, deserializer).map(Into::into)
+    }
+}
+
+impl<D: Owner, T> ForeignVec<D, T> {
+    /// Returns a [`ForeignVec`] over the elements of an archived vector, without copying them.
+    ///
+    /// `T` is the archived element type (e.g. `u8` or `rkyv::rend::i32_le`).
+    /// # Safety
+    /// This function is safe iff `owner` keeps the buffer containing `archived` alive and
+    /// unmodified until it is dropped (e.g. `owner` is an `Arc` of that buffer).
+    #[inline]
+    pub unsafe fn from_archived(archived: &ArchivedVec<T>, owner: D) -> Self {
+        // archived elements are never dropped: they are views into the buffer
+        Self::from_foreign_any(archived.as_ptr(), archived.len(), owner)
+    }
+}
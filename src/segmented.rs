@@ -0,0 +1,146 @@
+//! Contains [`SegmentedForeignVec`], a sequence composed of several regions.
+use core::ops::Index;
+
+use alloc::vec::Vec;
+
+use crate::ForeignVec;
+
+/// An immutable sequence composed of several, non-contiguous regions ("segments"), each of
+/// which may be native or foreign, e.g. a column delivered by a foreign producer in chunks.
+///
+/// The segments are not copied: elements are looked up by segment (in `O(log(segments))`).
+/// Use [`SegmentedForeignVec::coalesce`] to copy them into a single contiguous region.
+pub struct SegmentedForeignVec<D, T> {
+    segments: Vec<ForeignVec<D, T>>,
+    /// The end of each segment in the sequence, i.e. the cumulative lengths of the segments
+    ends: Vec<usize>,
+}
+
+impl<D, T> SegmentedForeignVec<D, T> {
+    /// Returns a new, empty [`SegmentedForeignVec`]. This does not allocate.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            segments: Vec::new(),
+            ends: Vec::new(),
+        }
+    }
+
+    /// Appends a segment, without copying it.
+    #[inline]
+    pub fn push(&mut self, segment: ForeignVec<D, T>) {
+        self.ends.push(self.len() + segment.len());
+        self.segments.push(segment);
+    }
+
+    /// Returns the total number of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    /// Returns whether there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the segments.
+    #[inline]
+    pub fn segments(&self) -> &[ForeignVec<D, T>] {
+        &self.segments
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        // the first segment ending after `index`; empty segments are skipped
+        let segment = self.ends.partition_point(|end| *end <= index);
+        let start = self.len_before(segment);
+        self.segments
+            .get(segment)
+            .map(|segment| &segment[index - start])
+    }
+
+    /// Returns an iterator over the elements of all segments, in order.
+    #[inline]
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.segments.iter().flat_map(|segment| segment.iter())
+    }
+
+    /// Consumes this sequence and returns its segments.
+    #[inline]
+    pub fn into_segments(self) -> Vec<ForeignVec<D, T>> {
+        self.segments
+    }
+
+    /// Returns a native [`ForeignVec`] with the elements of all segments, in order,
+    /// allocating once (see [`ForeignVec::concat`]).
+    #[inline]
+    pub fn coalesce(&self) -> ForeignVec<D, T>
+    where
+        T: Clone,
+    {
+        ForeignVec::concat(&self.segments)
+    }
+
+    #[inline]
+    fn len_before(&self, segment: usize) -> usize {
+        segment
+            .checked_sub(1)
+            .map_or(0, |previous| self.ends[previous])
+    }
+}
+
+impl<D, T> Default for SegmentedForeignVec<D, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D, T> Index<usize> for SegmentedForeignVec<D, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            ),
+        }
+    }
+}
+
+impl<D, T> FromIterator<ForeignVec<D, T>> for SegmentedForeignVec<D, T> {
+    /// Collects the segments, without copying them.
+    fn from_iter<I: IntoIterator<Item = ForeignVec<D, T>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        iter.into_iter().for_each(|segment| vec.push(segment));
+        vec
+    }
+}
+
+impl<D, T> From<Vec<ForeignVec<D, T>>> for SegmentedForeignVec<D, T> {
+    /// Converts the segments, without copying them.
+    #[inline]
+    fn from(segments: Vec<ForeignVec<D, T>>) -> Self {
+        let ends = segments
+            .iter()
+            .scan(0, |end, segment| {
+                *end += segment.len();
+                Some(*end)
+            })
+            .collect();
+        Self { segments, ends }
+    }
+}
+
+impl<D, T: core::fmt::Debug> core::fmt::Debug for SegmentedForeignVec<D, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,19 @@
+use alloc::vec::Vec;
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ForeignVec;
+
+/// Serializes as a sequence of `T`, regardless of how the region was allocated.
+impl<D, T: Serialize> Serialize for ForeignVec<D, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes from a sequence of `T` into a native allocation.
+impl<'de, D, T: Deserialize<'de>> Deserialize<'de> for ForeignVec<D, T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        Vec::<T>::deserialize(deserializer).map(Into::into)
+    }
+}
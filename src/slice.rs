@@ -0,0 +1,124 @@
+//! Contains [`ForeignSlice`], a region loaned by the foreign side.
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use crate::{validate_region, ForeignVec, ImportError, Owner};
+
+/// A region loaned by the foreign side for `'a`, e.g. for the duration of a callback,
+/// that behaves as `&'a [T]`.
+///
+/// Unlike a [`ForeignVec`], it has no owner: the foreign side retains ownership, and the
+/// region is never released by it. It is validated as in [`ForeignVec::try_from_foreign`],
+/// so that null pointers of empty regions are accepted.
+pub struct ForeignSlice<'a, T> {
+    ptr: NonNull<T>,
+    length: usize,
+    data: PhantomData<&'a [T]>,
+}
+
+// Safety: `ForeignSlice` behaves as a `&[T]`
+unsafe impl<T: Sync> Send for ForeignSlice<'_, T> {}
+
+// Safety: `ForeignSlice` behaves as a `&[T]`
+unsafe impl<T: Sync> Sync for ForeignSlice<'_, T> {}
+
+impl<'a, T> ForeignSlice<'a, T> {
+    /// Borrows a loaned memory region.
+    /// # Panics
+    /// This function panics if and only if [`ForeignSlice::try_from_raw_parts`] errors
+    /// # Safety
+    /// This function is safe if and only if `ptr` is valid for `length` and not mutated
+    /// for `'a`
+    #[inline]
+    pub unsafe fn from_raw_parts(ptr: *const T, length: usize) -> Self {
+        match Self::try_from_raw_parts(ptr, length) {
+            Ok(slice) => slice,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Borrows a loaned memory region, like [`ForeignSlice::from_raw_parts`], but returning
+    /// an error instead of panicking.
+    /// # Errors
+    /// This function errors if and only if [`ForeignVec::try_from_foreign`] would.
+    /// # Safety
+    /// As in [`ForeignSlice::from_raw_parts`]
+    #[inline]
+    pub unsafe fn try_from_raw_parts(ptr: *const T, length: usize) -> Result<Self, ImportError> {
+        Ok(Self {
+            ptr: validate_region(ptr, length)?,
+            length,
+            data: PhantomData,
+        })
+    }
+
+    /// Returns the region as a slice that lives for `'a`.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [T] {
+        // Safety: the region is valid for `'a`, as per the contract of the constructors
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.length) }
+    }
+
+    /// Returns a native [`ForeignVec`] with a copy of the region, that can outlive `'a`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_native<D>(&self) -> ForeignVec<D, T>
+    where
+        T: Clone,
+    {
+        self.as_slice().to_vec().into()
+    }
+
+    /// Takes ownership of the region by attaching an owner to it, without copying it,
+    /// as in [`ForeignVec::from_foreign`].
+    /// # Safety
+    /// This function is safe if and only if `owner` keeps the region valid and unmutated
+    /// until it is dropped, e.g. because it holds a reference acquired from the foreign side
+    /// that keeps the region alive past the loan.
+    #[inline]
+    pub unsafe fn into_foreign<D: Owner>(self, owner: D) -> ForeignVec<D, T>
+    where
+        T: Copy,
+    {
+        ForeignVec::from_foreign(self.ptr.as_ptr(), self.length, owner)
+    }
+}
+
+impl<T> Clone for ForeignSlice<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ForeignSlice<'_, T> {}
+
+impl<'a, T> From<&'a [T]> for ForeignSlice<'a, T> {
+    #[inline]
+    fn from(data: &'a [T]) -> Self {
+        // Safety: the region is valid for `'a`
+        unsafe { Self::from_raw_parts(data.as_ptr(), data.len()) }
+    }
+}
+
+impl<T> core::ops::Deref for ForeignSlice<'_, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for ForeignSlice<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for ForeignSlice<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
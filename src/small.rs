@@ -0,0 +1,200 @@
+//! Contains [`SmallForeignVec`], a [`ForeignVec`] that stores small regions inline.
+use core::mem::MaybeUninit;
+use core::ops::Deref;
+
+use alloc::vec::Vec;
+
+use crate::{Buffer, ForeignVec};
+
+/// Up to `N` elements stored inline.
+struct Inline<T, const N: usize> {
+    len: usize,
+    data: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> Inline<T, N> {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            len: 0,
+            data: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        // Safety: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: the first `len` elements are initialized
+        unsafe { core::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Moves the elements into a new [`Vec`] with room for `additional` more, leaving this empty.
+    fn spill(&mut self, additional: usize) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len + additional);
+        let len = core::mem::replace(&mut self.len, 0);
+        // Safety: the first `len` elements are initialized, and are moved (i.e. no longer
+        // owned by `self`) since `self.len` is 0
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.data.as_ptr() as *const T, vec.as_mut_ptr(), len);
+            vec.set_len(len);
+        }
+        vec
+    }
+}
+
+impl<T, const N: usize> Drop for Inline<T, N> {
+    fn drop(&mut self) {
+        // Safety: the first `len` elements are initialized and owned by `self`
+        unsafe { core::ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+enum Repr<D, T, const N: usize> {
+    Inline(Inline<T, N>),
+    Heap(ForeignVec<D, T>),
+}
+
+/// A [`ForeignVec`] that stores native regions of up to `N` elements inline, i.e. without
+/// allocating, falling back to a [`ForeignVec`] (native or foreign) otherwise.
+///
+/// This avoids the overhead of an allocation for each of many small buffers, at the cost
+/// of `N` elements of space (and a branch on each access).
+pub struct SmallForeignVec<D, T, const N: usize> {
+    repr: Repr<D, T, N>,
+}
+
+impl<D, T, const N: usize> SmallForeignVec<D, T, N> {
+    /// Returns a new, empty [`SmallForeignVec`], stored inline. This does not allocate.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            repr: Repr::Inline(Inline::new()),
+        }
+    }
+
+    /// Returns a [`SmallForeignVec`] with a copy of `data`, stored inline if it has up to `N`
+    /// elements.
+    pub fn from_slice(data: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        if data.len() > N {
+            return data.to_vec().into();
+        }
+        let mut inline = Inline::new();
+        for (slot, value) in inline.data.iter_mut().zip(data) {
+            slot.write(value.clone());
+            // incremented per element so that a panicking `clone` drops the previous ones
+            inline.len += 1;
+        }
+        Self {
+            repr: Repr::Inline(inline),
+        }
+    }
+
+    /// Returns whether the elements are stored inline.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline(_))
+    }
+
+    /// Appends `value`, moving the elements to a native [`ForeignVec`] once there are more than
+    /// `N` of them, as in [`Vec::push`]. A foreign region is copied into a native allocation
+    /// first (see [`ForeignVec::make_mut`]).
+    pub fn push(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        match &mut self.repr {
+            Repr::Inline(inline) if inline.len < N => {
+                inline.data[inline.len].write(value);
+                inline.len += 1;
+            }
+            Repr::Inline(inline) => {
+                let mut vec = inline.spill(N.max(1));
+                vec.push(value);
+                self.repr = Repr::Heap(vec.into());
+            }
+            Repr::Heap(vec) => vec.make_mut().push(value),
+        }
+    }
+
+    /// Returns the elements as a [`ForeignVec`], moving them into a new native allocation
+    /// if they are stored inline.
+    pub fn into_foreign_vec(self) -> ForeignVec<D, T> {
+        match self.repr {
+            Repr::Inline(mut inline) => inline.spill(0).into(),
+            Repr::Heap(vec) => vec,
+        }
+    }
+}
+
+impl<D, T, const N: usize> Deref for SmallForeignVec<D, T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        match &self.repr {
+            Repr::Inline(inline) => inline.as_slice(),
+            Repr::Heap(vec) => vec,
+        }
+    }
+}
+
+impl<D, T, const N: usize> Buffer<T> for SmallForeignVec<D, T, N> {
+    /// Inline and native regions can be mutated in place, foreign ones cannot.
+    #[inline]
+    fn try_as_mut_slice(&mut self) -> Option<&mut [T]> {
+        match &mut self.repr {
+            Repr::Inline(inline) => Some(inline.as_mut_slice()),
+            Repr::Heap(vec) => vec.try_as_mut_slice(),
+        }
+    }
+}
+
+impl<D, T, const N: usize> Default for SmallForeignVec<D, T, N> {
+    /// Returns an empty [`SmallForeignVec`], stored inline. This does not allocate.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D, T, const N: usize> From<ForeignVec<D, T>> for SmallForeignVec<D, T, N> {
+    /// Wraps `vec` as is, i.e. its elements are not moved inline.
+    #[inline]
+    fn from(vec: ForeignVec<D, T>) -> Self {
+        Self {
+            repr: Repr::Heap(vec),
+        }
+    }
+}
+
+impl<D, T, const N: usize> From<Vec<T>> for SmallForeignVec<D, T, N> {
+    /// Wraps `vec` as a native [`ForeignVec`], i.e. its elements are not moved inline.
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        ForeignVec::from(vec).into()
+    }
+}
+
+impl<D, T: Clone, const N: usize> FromIterator<T> for SmallForeignVec<D, T, N> {
+    /// Collects inline if the iterator yields up to `N` elements, and into a native
+    /// [`ForeignVec`] otherwise.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        iter.into_iter().for_each(|value| vec.push(value));
+        vec
+    }
+}
+
+impl<D, T: core::fmt::Debug, const N: usize> core::fmt::Debug for SmallForeignVec<D, T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,155 @@
+//! Contains [`Tracker`] and [`MockOwner`], utilities to test code that imports and
+//! releases foreign regions.
+//!
+//! ```
+//! use foreign_vec::testing::Tracker;
+//!
+//! let tracker = Tracker::new();
+//! let vec = tracker.import(&[1, 2, 3]);
+//! assert_eq!(tracker.live(), 1);
+//!
+//! // e.g. pass `vec` through the code under test
+//! drop(vec);
+//! tracker.assert_no_leaks();
+//! ```
+use std::any::Any;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::{ForeignVec, Owner, SharedOwner};
+
+struct State {
+    next_id: AtomicUsize,
+    live: Mutex<BTreeSet<usize>>,
+    released: AtomicUsize,
+}
+
+/// Tracks the [`MockOwner`]s it creates, so that leaked and doubly released owners
+/// can be detected. Clones of a [`Tracker`] track the same owners.
+#[derive(Clone)]
+pub struct Tracker {
+    state: Arc<State>,
+}
+
+impl Tracker {
+    /// Returns a new [`Tracker`] without owners.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(State {
+                next_id: AtomicUsize::new(0),
+                live: Mutex::new(BTreeSet::new()),
+                released: AtomicUsize::new(0),
+            }),
+        }
+    }
+
+    fn register(&self, region: Option<Arc<dyn Any + Send + Sync>>) -> MockOwner {
+        let id = self.state.next_id.fetch_add(1, Ordering::Relaxed);
+        self.state.live.lock().unwrap().insert(id);
+        MockOwner {
+            id,
+            tracker: self.clone(),
+            region,
+        }
+    }
+
+    /// Returns a new [`MockOwner`] that does not own a region, e.g. to own a region
+    /// that outlives it.
+    pub fn owner(&self) -> MockOwner {
+        self.register(None)
+    }
+
+    /// Returns a foreign [`ForeignVec`] over a copy of `data`, owned by a new [`MockOwner`].
+    pub fn import<T: Clone + Send + Sync + 'static>(&self, data: &[T]) -> ForeignVec<MockOwner, T> {
+        let region = Arc::new(data.to_vec());
+        let (ptr, length) = (region.as_ptr(), region.len());
+        let owner = self.register(Some(region));
+        // Safety: the region is valid for as long as the owner (and any of its clones), which
+        // drops its elements
+        unsafe { ForeignVec::from_foreign_any(ptr, length, owner) }
+    }
+
+    /// Returns the number of owners that were created and not yet released.
+    pub fn live(&self) -> usize {
+        self.state.live.lock().unwrap().len()
+    }
+
+    /// Returns the number of owners that were released.
+    pub fn released(&self) -> usize {
+        self.state.released.load(Ordering::Relaxed)
+    }
+
+    /// Asserts that every owner created by this tracker was released.
+    /// # Panics
+    /// This function panics if and only if an owner was not released.
+    #[track_caller]
+    pub fn assert_no_leaks(&self) {
+        let live = self.state.live.lock().unwrap();
+        assert!(
+            live.is_empty(),
+            "{} owner(s) were not released: {:?}",
+            live.len(),
+            live
+        );
+    }
+}
+
+impl Default for Tracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Tracker {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Tracker")
+            .field("live", &self.live())
+            .field("released", &self.released())
+            .finish()
+    }
+}
+
+/// An owner created by a [`Tracker`], that reports to it when it is released.
+///
+/// Releasing an owner twice (e.g. because it was duplicated with [`core::ptr::read`])
+/// panics. Clones of an owner are new owners, that share its region.
+pub struct MockOwner {
+    id: usize,
+    tracker: Tracker,
+    region: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl MockOwner {
+    /// Returns the id of this owner, unique within its [`Tracker`].
+    pub fn id(&self) -> usize {
+        self.id
+    }
+}
+
+impl Clone for MockOwner {
+    fn clone(&self) -> Self {
+        self.tracker.register(self.region.clone())
+    }
+}
+
+impl Drop for MockOwner {
+    fn drop(&mut self) {
+        let removed = self.tracker.state.live.lock().unwrap().remove(&self.id);
+        if !removed {
+            panic!("owner {} was released more than once", self.id);
+        }
+        self.tracker.state.released.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl core::fmt::Debug for MockOwner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MockOwner").field("id", &self.id).finish()
+    }
+}
+
+impl Owner for MockOwner {}
+
+// Safety: clones share the region via an `Arc`
+unsafe impl SharedOwner for MockOwner {}
@@ -0,0 +1,65 @@
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+use alloc::vec::Vec;
+
+use crate::native::{native_capacity, native_vec};
+use crate::ForeignVec;
+
+/// A mutable reference to the [`Vec<T>`] of a native [`ForeignVec`], returned by
+/// [`ForeignVec::get_vec`] and [`ForeignVec::make_mut`].
+///
+/// Changes to the [`Vec<T>`] (including reallocations) are written back to the
+/// [`ForeignVec`] when this is dropped. If this is leaked (e.g. via [`core::mem::forget`]),
+/// the [`ForeignVec`] is left empty and the [`Vec<T>`] is leaked.
+pub struct VecMut<'a, D, T> {
+    vec: Vec<T>,
+    parent: &'a mut ForeignVec<D, T>,
+}
+
+impl<'a, D, T> VecMut<'a, D, T> {
+    /// # Safety
+    /// `parent` must be native
+    pub(crate) unsafe fn new(parent: &'a mut ForeignVec<D, T>, capacity: usize) -> Self {
+        let vec = native_vec(parent.ptr, parent.length, capacity);
+        // the region is moved to `vec`; `parent` is left empty until this is dropped
+        parent.ptr = NonNull::dangling();
+        parent.length = 0;
+        parent.capacity = 0;
+        Self { vec, parent }
+    }
+}
+
+impl<D, T> Deref for VecMut<'_, D, T> {
+    type Target = Vec<T>;
+
+    #[inline]
+    fn deref(&self) -> &Vec<T> {
+        &self.vec
+    }
+}
+
+impl<D, T> DerefMut for VecMut<'_, D, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.vec
+    }
+}
+
+impl<D, T> Drop for VecMut<'_, D, T> {
+    #[inline]
+    fn drop(&mut self) {
+        let mut vec = ManuallyDrop::new(core::mem::take(&mut self.vec));
+        // Safety: the pointer of a `Vec` is never null
+        self.parent.ptr = unsafe { NonNull::new_unchecked(vec.as_mut_ptr()) };
+        self.parent.length = vec.len();
+        self.parent.capacity = native_capacity(&vec);
+    }
+}
+
+impl<D, T: core::fmt::Debug> core::fmt::Debug for VecMut<'_, D, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.vec, f)
+    }
+}
@@ -0,0 +1,22 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use foreign_vec::testing::MockOwner;
+use foreign_vec::ForeignVec;
+
+#[test]
+fn both_modes() {
+    let bytes = (0..=255u8).cycle().take(4096).collect::<Vec<_>>();
+    let mut u = Unstructured::new(&bytes);
+
+    let (mut native, mut foreign) = (0, 0);
+    while !u.is_empty() {
+        let vec = ForeignVec::<MockOwner, u16>::arbitrary(&mut u).unwrap();
+        if vec.is_foreign() {
+            foreign += 1;
+        } else {
+            native += 1;
+        }
+    }
+    assert!(native > 0);
+    assert!(foreign > 0);
+}
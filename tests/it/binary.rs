@@ -0,0 +1,63 @@
+use foreign_vec::binary::BinaryError;
+use foreign_vec::ForeignBinary;
+
+#[test]
+fn basics() {
+    let offsets = vec![0i32, 2, 2, 5];
+    let values = b"abcde".to_vec();
+    let array = ForeignBinary::<(), _>::try_new(offsets.into(), values.into()).unwrap();
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.value(0), b"ab");
+    assert_eq!(array.value(1), b"");
+    assert_eq!(array.get(2), Some(&b"cde"[..]));
+    assert_eq!(array.get(3), None);
+    assert_eq!(
+        array.iter().rev().collect::<Vec<_>>(),
+        [&b"cde"[..], b"", b"ab"]
+    );
+}
+
+#[test]
+fn sliced_large() {
+    // offsets need not start at zero
+    let offsets = vec![1i64, 3];
+    let values = b"abcd".to_vec();
+    let array = ForeignBinary::<(), _>::try_new(offsets.into(), values.into()).unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), [b"bc"]);
+}
+
+#[test]
+fn invalid() {
+    let try_new = |offsets: Vec<i32>, values: &[u8]| {
+        ForeignBinary::<(), _>::try_new(offsets.into(), values.to_vec().into()).map(|_| ())
+    };
+    assert_eq!(try_new(vec![], b""), Err(BinaryError::EmptyOffsets));
+    assert_eq!(
+        try_new(vec![-1, 0], b""),
+        Err(BinaryError::NegativeOffset { index: 0 })
+    );
+    assert_eq!(
+        try_new(vec![0, 2, -1], b"ab"),
+        Err(BinaryError::NegativeOffset { index: 2 })
+    );
+    assert_eq!(
+        try_new(vec![0, 2, 1], b"ab"),
+        Err(BinaryError::NotMonotonic { index: 2 })
+    );
+    assert_eq!(
+        try_new(vec![0, 3], b"ab"),
+        Err(BinaryError::OutOfBounds {
+            offset: 3,
+            values: 2
+        })
+    );
+}
+
+#[cfg(target_pointer_width = "32")]
+#[test]
+fn overflow() {
+    // offsets are validated as converted to `usize`, not as `i64`
+    let offsets = vec![0i64, 1 << 32];
+    let array = ForeignBinary::<(), _>::try_new(offsets.into(), b"a".to_vec().into());
+    assert_eq!(array.err(), Some(BinaryError::Overflow { index: 1 }));
+}
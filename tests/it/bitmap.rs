@@ -0,0 +1,62 @@
+use foreign_vec::ForeignBitmap;
+
+type Bitmap = ForeignBitmap<()>;
+
+#[test]
+fn basics() {
+    // 0b1010_1101, 0b0000_0111
+    let bitmap = Bitmap::new(vec![0b1010_1101u8, 0b0000_0111].into(), 0, 11);
+    assert_eq!(bitmap.len(), 11);
+    assert_eq!(bitmap.get(0), Some(true));
+    assert_eq!(bitmap.get(1), Some(false));
+    assert_eq!(bitmap.get(10), Some(true));
+    assert_eq!(bitmap.get(11), None);
+    assert_eq!(bitmap.count_ones(), 8);
+    assert_eq!(bitmap.count_zeros(), 3);
+    assert_eq!(
+        bitmap.iter().collect::<Vec<_>>(),
+        [true, false, true, true, false, true, false, true, true, true, true]
+    );
+    assert_eq!(
+        bitmap.set_indices().collect::<Vec<_>>(),
+        [0, 2, 3, 5, 7, 8, 9, 10]
+    );
+}
+
+#[test]
+fn offset() {
+    let bitmap = Bitmap::new(vec![0b1010_1101u8, 0b1111_0111, 0b1].into(), 3, 10);
+    let expected = [
+        true, false, true, false, true, true, true, true, false, true,
+    ];
+    assert_eq!(bitmap.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(bitmap.iter().rev().count(), 10);
+    assert_eq!(bitmap.count_ones(), 7);
+    let set = expected
+        .iter()
+        .enumerate()
+        .filter(|(_, bit)| **bit)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    assert_eq!(bitmap.set_indices().collect::<Vec<_>>(), set);
+
+    // within a single byte
+    let bitmap = Bitmap::new(vec![0b1010_1101u8].into(), 2, 4);
+    assert_eq!(bitmap.count_ones(), 3);
+    assert_eq!(bitmap.set_indices().collect::<Vec<_>>(), [0, 1, 3]);
+    assert_eq!(format!("{:?}", bitmap), "[true, true, false, true]");
+}
+
+#[test]
+fn empty() {
+    let bitmap = Bitmap::new(vec![0xFFu8].into(), 8, 0);
+    assert!(bitmap.is_empty());
+    assert_eq!(bitmap.count_ones(), 0);
+    assert_eq!(bitmap.set_indices().next(), None);
+}
+
+#[test]
+#[should_panic]
+fn out_of_bounds() {
+    Bitmap::new(vec![0u8].into(), 1, 8);
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use foreign_vec::Buffer;
+
+use super::{import_arc, MyForeignVec};
+
+/// A kernel generic over the storage of its input, that mutates it in place if possible.
+fn double<B: Buffer<i32>>(mut buffer: B) -> Vec<i32> {
+    match buffer.try_as_mut_slice() {
+        Some(values) => {
+            values.iter_mut().for_each(|x| *x *= 2);
+            values.to_vec()
+        }
+        None => buffer.iter().map(|x| x * 2).collect(),
+    }
+}
+
+#[test]
+fn generic() {
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let foreign = import_arc(&data);
+    assert_eq!(double(foreign), [2, 4]);
+    assert_eq!(*data, [1, 2]);
+
+    assert_eq!(double(MyForeignVec::from(vec![1, 2])), [2, 4]);
+    assert_eq!(double(vec![1, 2]), [2, 4]);
+    assert_eq!(double(&[1, 2][..]), [2, 4]);
+    assert_eq!(double(data.clone()), [2, 4]);
+}
+
+#[test]
+fn try_as_mut_slice() {
+    let mut vec = MyForeignVec::from(vec![1, 2]);
+    vec.try_as_mut_slice().unwrap()[0] = 3;
+    assert_eq!(vec, [3, 2]);
+
+    let mut data: Arc<[i32]> = vec![1, 2].into();
+    assert!(data.try_as_mut_slice().is_some());
+    let _clone = data.clone();
+    assert!(data.try_as_mut_slice().is_none());
+}
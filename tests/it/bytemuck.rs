@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use foreign_vec::{CastError, ForeignVec};
+
+use super::import_arc;
+
+#[test]
+fn as_bytes() {
+    let vec = import_arc(&Arc::from([1i32, 256]));
+    let expected = [1i32.to_ne_bytes(), 256i32.to_ne_bytes()].concat();
+    assert_eq!(vec.as_bytes(), expected);
+}
+
+#[test]
+fn cast_foreign() {
+    // backed by `u64`s, so that the region is aligned to `u64` regardless of the allocator
+    let data = vec![1u64, 2];
+    let vec = unsafe { ForeignVec::from_foreign(data.as_ptr(), data.len(), data) };
+    let vec = vec.try_cast::<u8>().unwrap();
+    assert_eq!(vec.len(), 16);
+    let vec = vec.try_cast::<i32>().unwrap();
+    assert_eq!(vec.len(), 4);
+    let vec = vec.try_cast::<u64>().unwrap();
+    assert_eq!(vec, [1, 2]);
+
+    // empty regions can be cast regardless of their alignment
+    let vec = unsafe { ForeignVec::from_foreign(std::ptr::null::<u8>(), 0, ()) };
+    let vec = vec.try_cast::<u64>().unwrap();
+    assert!(vec.is_empty());
+    assert!(vec.as_ptr().is_aligned());
+
+    let data = vec![1u64, 2];
+    let vec = unsafe { ForeignVec::from_foreign(data.as_ptr() as *const u8, 12, data) };
+    assert_eq!(
+        vec.try_cast::<u64>().map(|_| ()),
+        Err(CastError::SizeMismatch)
+    );
+}
+
+#[test]
+fn cast_native() {
+    let vec: ForeignVec<(), u32> = vec![1u32, 2].into();
+    let vec = vec.try_cast::<f32>().unwrap();
+    assert_eq!(vec.len(), 2);
+
+    let vec: ForeignVec<(), u8> = vec![0u8; 8].into();
+    assert_eq!(
+        vec.try_cast::<u64>().map(|_| ()),
+        Err(CastError::AlignmentMismatch)
+    );
+}
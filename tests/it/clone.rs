@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use foreign_vec::ForeignVec;
+
+use super::{import_arc, MyForeignVec};
+
+#[test]
+fn deep() {
+    let mut vec: MyForeignVec = vec![1, 2].into();
+    let mut cloned = vec.clone();
+    assert_ne!(vec.as_ptr(), cloned.as_ptr());
+    cloned.get_vec().unwrap().push(3);
+    assert_eq!(&*vec, &[1, 2]);
+    assert!(vec.get_vec().is_some());
+}
+
+#[test]
+fn shared() {
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let mut vec = import_arc(&data);
+    let mut cloned = vec.clone_shared();
+    assert_eq!(vec.as_ptr(), cloned.as_ptr());
+    assert_eq!(Arc::strong_count(&data), 3);
+    assert!(cloned.get_vec().is_none());
+
+    // a deep clone of a foreign buffer is native
+    assert!(vec.clone().get_vec().is_some());
+    assert!(vec.get_vec().is_none());
+
+    drop(vec);
+    drop(cloned);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn shared_native() {
+    let vec: ForeignVec<Arc<()>, i32> = vec![1, 2].into();
+    let cloned = vec.clone_shared();
+    assert_ne!(vec.as_ptr(), cloned.as_ptr());
+    assert_eq!(&*cloned, &[1, 2]);
+}
+
+#[test]
+fn into_native() {
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let vec = import_arc(&data);
+    let mut vec = vec.into_native();
+    assert_eq!(Arc::strong_count(&data), 1);
+    assert_ne!(vec.as_ptr(), data.as_ptr());
+    assert_eq!(vec, [1, 2]);
+    assert!(vec.get_vec().is_some());
+
+    let ptr = vec.as_ptr();
+    assert_eq!(vec.into_native().as_ptr(), ptr);
+}
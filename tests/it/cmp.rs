@@ -0,0 +1,88 @@
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
+use foreign_vec::ForeignVec;
+
+use super::{import_arc, MyForeignVec};
+
+#[test]
+fn eq() {
+    let vec: MyForeignVec = vec![1, 2].into();
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let foreign = import_arc(&data);
+
+    // across owners
+    assert_eq!(vec, foreign);
+    assert_ne!(vec, MyForeignVec::from(vec![1]));
+
+    // against slices, vectors and arrays, in both directions
+    assert_eq!(vec, [1, 2]);
+    assert_eq!(vec, &[1, 2]);
+    assert_eq!(vec, vec![1, 2]);
+    assert_eq!(vec, &[1, 2][..]);
+    assert_eq!([1, 2], vec);
+    assert_eq!(vec![1, 2], vec);
+    assert_eq!(&[1, 2][..], vec);
+}
+
+#[test]
+fn ord() {
+    let a: MyForeignVec = vec![1, 2].into();
+    let b: MyForeignVec = vec![1, 3].into();
+    assert!(a < b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Less);
+
+    let set = [b, a].into_iter().collect::<BTreeSet<_>>();
+    assert_eq!(set.into_iter().next().unwrap(), [1, 2]);
+}
+
+#[test]
+fn hash() {
+    let set = [
+        MyForeignVec::from(vec![1, 2]),
+        MyForeignVec::from(vec![1, 2]),
+    ]
+    .into_iter()
+    .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn borrow() {
+    fn len(data: impl AsRef<[i32]>) -> usize {
+        data.as_ref().len()
+    }
+    assert_eq!(len(MyForeignVec::from(vec![1, 2])), 2);
+
+    // looked up by slice
+    let set = [MyForeignVec::from(vec![1, 2])]
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert!(set.contains(&[1, 2][..]));
+    let set = [MyForeignVec::from(vec![1, 2])]
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+    assert!(set.contains(&[1, 2][..]));
+}
+
+#[test]
+fn ptr_eq() {
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    let mut a = import_arc(&data);
+    let b = a.clone_shared();
+    assert!(a.ptr_eq(&b));
+
+    // equal elements, different regions
+    let native = ForeignVec::from(vec![1, 2, 3]);
+    assert_eq!(a, native);
+    assert!(!a.ptr_eq(&native));
+
+    // same start, different length
+    let c = a.split_off(2);
+    assert!(!a.ptr_eq(&b));
+
+    assert!(b.contains_ptr(&data[2]));
+    assert!(c.contains_ptr(&data[2]));
+    assert!(!a.contains_ptr(&data[2]));
+    assert!(!b.contains_ptr(data.as_ptr_range().end));
+}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use foreign_vec::cuda::DeviceForeignVec;
+
+// device memory is mocked by host memory, which is never dereferenced
+#[test]
+fn device() {
+    let data: Arc<[u32]> = vec![1, 2, 3].into();
+    let vec = unsafe { DeviceForeignVec::from_device(data.as_ptr(), data.len(), data.clone()) };
+    assert_eq!(vec.as_ptr(), data.as_ptr());
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.len_bytes(), 12);
+    assert!(!vec.is_empty());
+    assert_eq!(Arc::strong_count(vec.owner()), 2);
+    drop(vec);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
@@ -0,0 +1,17 @@
+use foreign_vec::ForeignVec;
+
+const fn assert_format<T: defmt::Format>() {}
+
+// `defmt` cannot log on the host, so this only checks that structs containing a
+// `ForeignVec` can derive `Format`
+#[derive(defmt::Format)]
+#[allow(dead_code)]
+struct Frame {
+    id: u8,
+    payload: ForeignVec<(), u8>,
+}
+
+const _: () = {
+    assert_format::<ForeignVec<(), u8>>();
+    assert_format::<Frame>();
+};
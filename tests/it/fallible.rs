@@ -0,0 +1,28 @@
+use std::collections::TryReserveError;
+use std::sync::Arc;
+
+use foreign_vec::ForeignVec;
+
+use super::import_arc;
+
+#[test]
+fn success() -> Result<(), TryReserveError> {
+    let data: Arc<[u64]> = Arc::new([1, 2]);
+    let mut vec = import_arc(&data);
+    vec.try_make_mut()?.push(3);
+    assert_eq!(vec, [1, 2, 3]);
+    assert!(!vec.is_foreign());
+
+    let vec = import_arc(&data).try_into_native().map_err(|(_, e)| e)?;
+    assert!(!vec.is_foreign());
+    let ptr = vec.as_ptr();
+    let vec = vec.try_into_native().map_err(|(_, e)| e)?;
+    assert_eq!(vec.as_ptr(), ptr);
+
+    let mut vec = ForeignVec::try_concat(&[import_arc(&data), vec![3].into()])?;
+    assert_eq!(vec, [1, 2, 3]);
+
+    vec.try_append(&mut import_arc(&data))?;
+    assert_eq!(vec, [1, 2, 3, 1, 2]);
+    Ok(())
+}
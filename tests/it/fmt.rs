@@ -0,0 +1,42 @@
+use foreign_vec::ForeignVec;
+
+#[test]
+fn truncated() {
+    let vec: ForeignVec<(), usize> = (0..5000).collect();
+    let expected = format!(
+        "[{}, … 5000 values]",
+        (0..4096)
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    assert_eq!(format!("{:?}", vec), expected);
+
+    // in full with `{:#?}`
+    assert_eq!(
+        format!("{:#?}", vec),
+        format!("{:#?}", (0..5000).collect::<Vec<_>>())
+    );
+
+    let vec: ForeignVec<(), usize> = (0..4096).collect();
+    assert_eq!(
+        format!("{:?}", vec),
+        format!("{:?}", (0..4096).collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn verbose() {
+    let vec: ForeignVec<(), i32> = Vec::with_capacity(4).into();
+    assert_eq!(
+        format!("{:?}", vec.verbose()),
+        "ForeignVec { allocation: Native, capacity: 4, len: 0, values: [] }"
+    );
+
+    let data = [1, 2];
+    let vec = unsafe { ForeignVec::from_foreign(data.as_ptr(), data.len(), "owner") };
+    assert_eq!(
+        format!("{:?}", vec.verbose()),
+        "ForeignVec { allocation: Foreign, owner: \"owner\", len: 2, values: [1, 2] }"
+    );
+}
@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+
+use foreign_vec::{ForeignVec, NoOwner};
+
+#[test]
+fn boxed() {
+    let data: Box<[i32]> = vec![1, 2].into_boxed_slice();
+    let ptr = data.as_ptr();
+    let mut vec: ForeignVec<(), i32> = data.into();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert!(vec.get_vec().is_some());
+}
+
+#[test]
+fn cow() {
+    static DATA: [i32; 2] = [1, 2];
+    let mut vec: ForeignVec<NoOwner, i32> = Cow::Borrowed(&DATA[..]).into();
+    assert_eq!(vec.as_ptr(), DATA.as_ptr());
+    assert!(vec.get_vec().is_none());
+
+    let mut vec: ForeignVec<NoOwner, i32> = Cow::<[i32]>::Owned(vec![1, 2]).into();
+    assert_eq!(vec, DATA);
+    assert!(vec.get_vec().is_some());
+}
+
+#[test]
+fn arc() {
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let mut vec: ForeignVec<_, _> = data.clone().into();
+    assert_eq!(vec.as_ptr(), data.as_ptr());
+    assert_eq!(Arc::strong_count(&data), 2);
+    assert!(vec.get_vec().is_none());
+
+    let cloned = vec.clone_shared();
+    assert_eq!(Arc::strong_count(&data), 3);
+    drop(vec);
+    drop(cloned);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn from_static() {
+    static TABLE: [u8; 4] = [1, 2, 4, 8];
+    let mut vec = ForeignVec::from_static(&TABLE);
+    assert_eq!(vec.as_ptr(), TABLE.as_ptr());
+    assert_eq!(vec, TABLE);
+    assert!(vec.get_vec().is_none());
+
+    assert!(ForeignVec::<_, i32>::from_static(&[]).is_empty());
+}
+
+#[test]
+fn leak() {
+    // the leaked regions are kept reachable so that they are not reported as leaks
+    static LEAKED: Mutex<Vec<&'static [i32]>> = Mutex::new(Vec::new());
+
+    let vec: ForeignVec<(), i32> = vec![1, 2].into();
+    let leaked: &'static [i32] = vec.leak();
+    assert_eq!(leaked, [1, 2]);
+    LEAKED.lock().unwrap().push(leaked);
+
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let vec: ForeignVec<_, _> = data.clone().into();
+    let leaked = vec.leak();
+    assert_eq!(leaked.as_ptr(), data.as_ptr());
+    // the owner is never dropped
+    assert_eq!(Arc::strong_count(&data), 2);
+    LEAKED.lock().unwrap().push(leaked);
+}
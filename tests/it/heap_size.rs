@@ -0,0 +1,54 @@
+use std::mem::size_of;
+use std::sync::Arc;
+
+use foreign_vec::{AllocationKind, ForeignVec, HeapSize, NoOwner};
+
+#[test]
+fn native() {
+    let mut data = Vec::with_capacity(4);
+    data.extend([1i32, 2]);
+    let vec: ForeignVec<(), i32> = data.into();
+    assert!(!vec.is_foreign());
+    assert_eq!(vec.allocated_bytes(), 4 * size_of::<i32>());
+    assert_eq!(vec.heap_size(), 4 * size_of::<i32>());
+
+    assert_eq!(ForeignVec::<(), i32>::new().heap_size(), 0);
+}
+
+#[test]
+fn foreign() {
+    // the owner pins more than the region
+    let data: Arc<[i32]> = vec![1, 2, 3, 4].into();
+    let mut vec: ForeignVec<_, _> = data.clone().into();
+    vec.truncate(1);
+    assert!(vec.is_foreign());
+    assert_eq!(vec.allocated_bytes(), 0);
+    assert_eq!(vec.heap_size(), 4 * size_of::<i32>());
+
+    assert_eq!(ForeignVec::from_static(&[1, 2]).heap_size(), 0);
+    assert_eq!(NoOwner.heap_size(), 0);
+}
+
+#[test]
+fn nested() {
+    // a buffer owning a slice of another buffer
+    let inner: ForeignVec<(), u8> = vec![0; 16].into();
+    let ptr = inner[8..].as_ptr();
+    let vec = unsafe { ForeignVec::from_foreign(ptr, 8, inner) };
+    assert_eq!(vec.heap_size(), 16);
+}
+
+#[test]
+fn allocation() {
+    let vec: ForeignVec<(), i32> = Vec::with_capacity(4).into();
+    assert_eq!(vec.allocation(), AllocationKind::Native);
+    assert_eq!(vec.capacity(), 4);
+
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let vec: ForeignVec<_, _> = data.into();
+    assert_eq!(vec.allocation(), AllocationKind::Foreign);
+    assert_eq!(vec.capacity(), 2);
+
+    let vec: ForeignVec<(), ()> = vec![(); 2].into();
+    assert_eq!(vec.capacity(), usize::MAX);
+}
@@ -0,0 +1,97 @@
+use std::sync::{Arc, Mutex};
+
+use foreign_vec::hooks::{set_hooks, Hooks};
+use foreign_vec::ForeignVec;
+
+use super::import_arc;
+
+/// Records all events; tests filter them by region, since other tests run concurrently.
+struct Recorder {
+    events: Mutex<Vec<(&'static str, usize, usize)>>,
+}
+
+impl Hooks for Recorder {
+    fn on_import(&self, ptr: *const u8, len_bytes: usize) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(("import", ptr as usize, len_bytes));
+    }
+
+    fn on_release(&self, ptr: *const u8, len_bytes: usize) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(("release", ptr as usize, len_bytes));
+    }
+}
+
+static RECORDER: Recorder = Recorder {
+    events: Mutex::new(Vec::new()),
+};
+
+fn events_of(data: &[i32]) -> Vec<(&'static str, usize)> {
+    let start = data.as_ptr() as usize;
+    let end = start + std::mem::size_of_val(data);
+    RECORDER
+        .events
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, ptr, _)| (start..end).contains(ptr))
+        .map(|(event, _, len_bytes)| (*event, *len_bytes))
+        .collect()
+}
+
+#[test]
+fn lifecycle() {
+    set_hooks(&RECORDER);
+
+    let data = vec![1i32, 2, 3];
+    let mut vec = unsafe { ForeignVec::from_foreign(data.as_ptr(), data.len(), ()) };
+    assert_eq!(events_of(&data), [("import", 12)]);
+
+    // the region is released as it was imported
+    vec.advance(1);
+    drop(vec);
+    assert_eq!(events_of(&data), [("import", 12), ("release", 12)]);
+
+    // native and `'static` regions are not reported
+    let native: ForeignVec<(), i32> = vec![1].into();
+    drop(native);
+    static DATA: [i32; 2] = [1, 2];
+    drop(ForeignVec::from_static(&DATA));
+    assert!(events_of(&DATA).is_empty());
+}
+
+#[test]
+fn shared() {
+    set_hooks(&RECORDER);
+
+    // regions shared with buffers that were not imported are not reported either
+    static DATA: [i32; 3] = [1, 2, 3];
+    let mut vec = ForeignVec::from_static(&DATA);
+    let clone = vec.clone_shared();
+    let chunks = clone.chunks_shared(2).collect::<Vec<_>>();
+    let tail = vec.split_off(1);
+    drop((vec, clone, chunks, tail));
+    assert!(events_of(&DATA).is_empty());
+
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    drop(ForeignVec::from(data.clone()).clone_shared());
+    assert!(events_of(&data).is_empty());
+
+    // while regions shared with imported buffers are reported as imported by each buffer
+    let vec = import_arc(&data);
+    drop(vec.clone_shared());
+    drop(vec);
+    assert_eq!(
+        events_of(&data),
+        [
+            ("import", 12),
+            ("import", 12),
+            ("release", 12),
+            ("release", 12)
+        ]
+    );
+}
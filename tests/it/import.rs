@@ -0,0 +1,123 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use foreign_vec::{ExclusiveOwner, ForeignVec, ImportError, Owner};
+
+static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+struct Release;
+
+impl Drop for Release {
+    fn drop(&mut self) {
+        RELEASED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl Owner for Release {}
+
+#[test]
+fn null() {
+    let vec = unsafe { ForeignVec::<_, i32>::try_from_foreign(std::ptr::null(), 0, ()) };
+    assert!(vec.unwrap().is_empty());
+
+    let vec = unsafe { ForeignVec::<_, i32>::from_foreign(std::ptr::null(), 0, ()) };
+    assert!(vec.is_empty());
+
+    let before = RELEASED.load(Ordering::SeqCst);
+    let vec = unsafe { ForeignVec::<_, i32>::try_from_foreign(std::ptr::null(), 1, Release) };
+    assert_eq!(vec.map(|_| ()), Err(ImportError::Null));
+    // the owner is released on error
+    assert_eq!(RELEASED.load(Ordering::SeqCst), before + 1);
+}
+
+#[test]
+#[should_panic]
+fn null_panics() {
+    unsafe { ForeignVec::<_, i32>::from_foreign(std::ptr::null(), 1, ()) };
+}
+
+#[test]
+fn misaligned() {
+    let data = [0u64; 2];
+    let ptr = (data.as_ptr() as *const u8).wrapping_add(1) as *const u32;
+    let vec = unsafe { ForeignVec::<_, u32>::try_from_foreign(ptr, 1, ()) };
+    assert_eq!(vec.map(|_| ()), Err(ImportError::Misaligned));
+}
+
+#[test]
+fn too_large() {
+    let data = [0u64; 1];
+    let length = isize::MAX as usize / 8 + 1;
+    let vec = unsafe { ForeignVec::<_, u64>::try_from_foreign(data.as_ptr(), length, ()) };
+    assert_eq!(vec.map(|_| ()), Err(ImportError::TooLarge));
+}
+
+#[test]
+fn zero_sized() {
+    // any pointer is valid for zero-sized types
+    let vec = unsafe { ForeignVec::<_, ()>::from_foreign(std::ptr::null(), usize::MAX, ()) };
+    assert_eq!(vec.len(), usize::MAX);
+    assert_eq!(vec[usize::MAX - 1], ());
+}
+
+#[test]
+fn as_mut_slice_unchecked() {
+    // a region allocated for us to fill
+    let mut data = vec![0i32; 3];
+    let ptr = data.as_mut_ptr();
+    let mut vec = unsafe { ForeignVec::from_foreign(ptr, 3, data) };
+    unsafe { vec.as_mut_slice_unchecked() }.copy_from_slice(&[1, 2, 3]);
+    assert_eq!(vec, [1, 2, 3]);
+
+    let mut vec = ForeignVec::<(), _>::from(vec![1, 2]);
+    let slice = unsafe { vec.as_mut_slice_unchecked() };
+    slice[0] = 3;
+    assert_eq!(vec, [3, 2]);
+}
+
+/// An allocation made for us to fill, that is not referenced by anyone else
+struct Exclusive(#[allow(dead_code)] Vec<i32>);
+
+impl Owner for Exclusive {}
+
+// Safety: the allocation is only referenced by the region imported with it
+unsafe impl ExclusiveOwner for Exclusive {}
+
+#[test]
+fn as_mut_slice() {
+    let mut data = vec![0i32; 3];
+    let ptr = data.as_mut_ptr();
+    let mut vec = unsafe { ForeignVec::from_foreign(ptr, 3, Exclusive(data)) };
+    vec.as_mut_slice().copy_from_slice(&[1, 2, 3]);
+    vec.truncate(2);
+    assert_eq!(vec, [1, 2]);
+
+    let mut vec = ForeignVec::<Exclusive, _>::from(vec![1, 2]);
+    vec.as_mut_slice()[0] = 3;
+    assert_eq!(vec, [3, 2]);
+}
+
+#[test]
+fn from_foreign_any() {
+    // the owner drops the elements, which `ForeignVec` does not
+    let data = Arc::new(vec!["a".to_string(), "b".to_string()]);
+    let vec = unsafe { ForeignVec::from_foreign_any(data.as_ptr(), data.len(), data.clone()) };
+    assert_eq!(vec, ["a", "b"]);
+    drop(vec);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn null_terminated() {
+    let data = b"hello\0world";
+    let import = |max_len| unsafe { ForeignVec::from_null_terminated(data.as_ptr(), max_len, ()) };
+    assert_eq!(import(None), b"hello");
+    assert_eq!(import(Some(3)), b"hel");
+    assert_eq!(import(Some(8)), b"hello");
+
+    let vec = unsafe { ForeignVec::from_null_terminated(c"".as_ptr().cast(), None, ()) };
+    assert!(vec.is_empty());
+
+    let vec = unsafe { ForeignVec::from_null_terminated(std::ptr::null(), None, ()) };
+    assert!(vec.is_empty());
+}
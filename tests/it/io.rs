@@ -0,0 +1,111 @@
+use foreign_vec::io::Cursor;
+
+#[test]
+fn position() {
+    let mut cursor = Cursor::<()>::new(b"abc".to_vec().into());
+    assert_eq!(cursor.remaining(), b"abc");
+    cursor.set_position(1);
+    assert_eq!(cursor.remaining(), b"bc");
+    cursor.set_position(10);
+    assert_eq!(cursor.remaining(), b"");
+    assert_eq!(&*cursor.into_inner(), b"abc");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read() {
+    use std::io::{BufRead, Read, Seek, SeekFrom};
+
+    use foreign_vec::ForeignVec;
+
+    // a region owned by someone else that outlives the cursor
+    let data = b"hello\nworld".to_vec();
+    let vec = unsafe { ForeignVec::<(), u8>::from_foreign(data.as_ptr(), data.len(), ()) };
+    let mut cursor = Cursor::new(vec);
+
+    let mut line = String::new();
+    cursor.read_line(&mut line).unwrap();
+    assert_eq!(line, "hello\n");
+
+    let mut rest = vec![];
+    cursor.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, b"world");
+
+    assert_eq!(cursor.seek(SeekFrom::End(-3)).unwrap(), 8);
+    let mut buf = [0; 3];
+    cursor.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"rld");
+    assert!(cursor.read_exact(&mut buf).is_err());
+    assert!(cursor.seek(SeekFrom::Current(-100)).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn read_consuming() {
+    use std::io::Read;
+
+    use foreign_vec::ForeignVec;
+
+    // reading a foreign region moves the start of the view without copying it
+    let data = b"hello world".to_vec();
+    let mut vec = unsafe { ForeignVec::<(), u8>::from_foreign(data.as_ptr(), data.len(), ()) };
+    let mut buf = [0; 6];
+    vec.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello ");
+    assert_eq!(vec.as_ptr(), data[6..].as_ptr());
+    assert!(vec.read_exact(&mut buf).is_err());
+    assert_eq!(vec, b"world");
+
+    let mut native: ForeignVec<(), u8> = b"abc".to_vec().into();
+    assert_eq!(native.read(&mut buf).unwrap(), 3);
+    assert_eq!(&buf[..3], b"abc");
+    assert!(native.is_empty());
+    assert_eq!(native.read(&mut buf).unwrap(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write() {
+    use std::io::Write;
+
+    use foreign_vec::ForeignVec;
+
+    let mut vec = ForeignVec::<(), u8>::new();
+    write!(vec, "{}-{}", 1, 2).unwrap();
+    vec.write_all(b"!").unwrap();
+    assert_eq!(vec, b"1-2!");
+
+    // a foreign region is copied before it is appended to
+    let data = b"hello".to_vec();
+    let mut vec = unsafe { ForeignVec::from_foreign(data.as_ptr(), data.len(), ()) };
+    vec.write_all(b" world").unwrap();
+    assert!(!vec.is_foreign());
+    assert_eq!(vec, b"hello world");
+    assert_eq!(data, b"hello");
+}
+
+#[test]
+fn read_numbers() {
+    let mut data = vec![7u8];
+    data.extend(1u32.to_le_bytes());
+    data.extend(2u32.to_be_bytes());
+    data.extend(1.5f64.to_le_bytes());
+    data.extend((-3i16).to_be_bytes());
+    data.extend(b"abc");
+    let mut cursor = Cursor::<()>::new(data.into());
+
+    assert_eq!(cursor.read_u8(), Some(7));
+    // the remaining values are misaligned
+    assert_eq!(cursor.read_u32_le(), Some(1));
+    assert_eq!(cursor.read_u32_be(), Some(2));
+    assert_eq!(cursor.read_f64_le(), Some(1.5));
+    assert_eq!(cursor.read_i16_be(), Some(-3));
+    assert_eq!(cursor.position(), 19);
+
+    // reading past the end does not advance
+    assert_eq!(cursor.read_u64_le(), None);
+    assert_eq!(cursor.read_slice(4), None);
+    assert_eq!(cursor.read_slice(2), Some(&b"ab"[..]));
+    assert_eq!(cursor.read_array(), Some(*b"c"));
+    assert_eq!(cursor.read_i8(), None);
+}
@@ -0,0 +1,52 @@
+use foreign_vec::io_uring::FixedBuffers;
+
+#[test]
+fn import() {
+    let buffers = FixedBuffers::new(2, 8);
+    let iovecs = buffers.iovecs();
+    assert_eq!(iovecs.len(), 2);
+    assert_eq!(iovecs[1].iov_base as *mut u8, buffers.as_mut_ptr(1));
+    assert_eq!(iovecs[1].iov_len, 8);
+
+    // mocking a completed `ReadFixed` into buffer 1
+    unsafe { buffers.as_mut_ptr(1).copy_from(b"hello".as_ptr(), 5) };
+    let vec = unsafe { buffers.import(1, 5) };
+    assert_eq!(vec, b"hello");
+    assert_eq!(vec.buf_index(), Some(1));
+
+    // the buffers outlive the handle
+    drop(buffers);
+    let chunks = vec.chunks_shared(2).collect::<Vec<_>>();
+    drop(vec);
+    assert_eq!(chunks[2], b"o");
+}
+
+#[test]
+fn align() {
+    // page-aligned by default
+    let buffers = FixedBuffers::new(2, 8);
+    assert!(buffers.align() >= 4096);
+    assert!((buffers.as_mut_ptr(1) as usize).is_multiple_of(buffers.align()));
+
+    let buffers = FixedBuffers::with_align(2, 100, 64);
+    assert!((buffers.as_mut_ptr(0) as usize).is_multiple_of(64));
+    assert!((buffers.as_mut_ptr(1) as usize).is_multiple_of(64));
+    let vec = unsafe { buffers.import(1, 100) };
+    assert_eq!(vec, [0; 100]);
+
+    let buffers = FixedBuffers::with_align(1, 0, 64);
+    assert!(unsafe { buffers.import(0, 0) }.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn invalid_align() {
+    let _ = FixedBuffers::with_align(1, 8, 3);
+}
+
+#[test]
+#[should_panic]
+fn import_too_large() {
+    let buffers = FixedBuffers::new(1, 8);
+    let _ = unsafe { buffers.import(0, 9) };
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use foreign_vec::ForeignVec;
+
+use super::MyForeignVec;
+
+#[test]
+fn by_ref() {
+    let vec: MyForeignVec = vec![1, 2].into();
+    let mut sum = 0;
+    for x in &vec {
+        sum += x;
+    }
+    assert_eq!(sum, 3);
+}
+
+#[test]
+fn native() {
+    let vec: ForeignVec<(), String> = vec!["a".to_string(), "b".to_string()].into();
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back().unwrap(), "b");
+    assert_eq!(iter.collect::<Vec<_>>(), ["a"]);
+}
+
+#[test]
+fn foreign() {
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    let vec = ForeignVec::from(data.clone());
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.len(), 1);
+    // the owner is alive for as long as the iterator
+    assert_eq!(Arc::strong_count(&data), 2);
+    drop(iter);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn collect() {
+    let mut vec: MyForeignVec = (1..4).collect();
+    assert_eq!(vec, [1, 2, 3]);
+    assert!(vec.get_vec().is_some());
+}
+
+#[test]
+fn extend() {
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let mut vec = ForeignVec::from(data.clone());
+    vec.extend([3]);
+    vec.extend(&[4]);
+    assert_eq!(vec, [1, 2, 3, 4]);
+    // the foreign region was copied and its owner released
+    assert!(vec.get_vec().is_some());
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn make_mut() {
+    let data: Arc<[i32]> = vec![1, 2].into();
+    let mut vec = ForeignVec::from(data);
+    vec.make_mut()[0] = 3;
+    assert_eq!(vec, [3, 2]);
+}
+
+#[test]
+fn concat() {
+    let data = [3, 4];
+    let foreign = unsafe { ForeignVec::<(), _>::from_foreign(data.as_ptr(), data.len(), ()) };
+    let parts = [vec![1, 2].into(), foreign, ForeignVec::new()];
+    let mut vec = ForeignVec::concat(&parts);
+    assert_eq!(vec, [1, 2, 3, 4]);
+    assert_eq!(vec.get_vec().unwrap().capacity(), 4);
+}
+
+#[test]
+fn append() {
+    let data = [3, 4];
+    let mut foreign = unsafe { ForeignVec::<(), _>::from_foreign(data.as_ptr(), data.len(), ()) };
+    let mut vec: ForeignVec<(), i32> = vec![1, 2].into();
+    vec.append(&mut foreign);
+    assert_eq!(vec, [1, 2, 3, 4]);
+    assert!(foreign.is_empty());
+    assert!(!foreign.is_foreign());
+
+    let mut native: ForeignVec<(), i32> = vec![5].into();
+    vec.append(&mut native);
+    assert_eq!(vec, [1, 2, 3, 4, 5]);
+    assert!(native.is_empty());
+}
@@ -1,4 +1,61 @@
-use foreign_vec::ForeignVec;
+// the tests use native buffers throughout; see `tests/no_alloc.rs` for the foreign-only mode
+#![cfg(feature = "alloc")]
+
+use std::sync::Arc;
+
+use foreign_vec::{ForeignVec, Owner};
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod binary;
+mod bitmap;
+mod buffer;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+mod clone;
+mod cmp;
+#[cfg(feature = "cuda")]
+mod cuda;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod fallible;
+mod fmt;
+mod from;
+mod heap_size;
+#[cfg(feature = "hooks")]
+mod hooks;
+mod import;
+mod io;
+#[cfg(all(feature = "io_uring", target_os = "linux"))]
+mod io_uring;
+mod iter;
+mod matrix;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+mod owner_handle;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(all(feature = "protect", target_os = "linux"))]
+mod protect;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "registry")]
+mod registry;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+mod segmented;
+mod send_sync;
+#[cfg(feature = "serde")]
+mod serde;
+mod slice;
+mod small;
+#[cfg(feature = "stable_deref_trait")]
+mod stable_deref;
+#[cfg(feature = "testing")]
+mod testing;
+mod uninit;
+mod variance;
+mod window;
 
 // say that we have a foreign struct allocated by an external allocator (e.g. C++)
 // owning an immutable memory region
@@ -20,10 +77,18 @@ impl Drop for Foreign {
     }
 }
 
+// which keeps the region alive, and is thus an `Owner`
+impl Owner for Foreign {}
+
 // The type that we use on the library uses `foreign_vec`
 // this could be a generic over `T` when the in-memory format supports multiple types.
 type MyForeignVec = ForeignVec<Foreign, i32>;
 
+/// Imports `data` as a foreign region via [`ForeignVec::from_foreign`], owned by a clone of it.
+fn import_arc<T: Copy>(data: &Arc<[T]>) -> ForeignVec<Arc<[T]>, T> {
+    unsafe { ForeignVec::from_foreign(data.as_ptr(), data.len(), data.clone()) }
+}
+
 #[test]
 fn test_vec() {
     // we can use it with `Vec`:
@@ -40,7 +105,7 @@ fn test_vec() {
     assert_eq!(format!("{:?}", vec), "[1, 2]");
 
     // you can retrieve a mut vec (since it is allocated by Rust)
-    assert_eq!(vec.get_vec(), Some(&mut vec![1, 2]));
+    assert_eq!(vec.get_vec().as_deref(), Some(&vec![1, 2]));
 
     // this calls `Vec::drop`, as usual
     drop(vec)
@@ -71,8 +136,81 @@ fn test_foreign() {
     // create a `MyForeignVec` from a foreign that implements `Deref`.
     let mut vec = unsafe { MyForeignVec::from_foreign(a.ptr, a.length, a) };
     assert_eq!(&*vec, expected);
-    assert_eq!(vec.get_vec(), None);
+    assert!(vec.get_vec().is_none());
 
     // this calls `Foreign::drop`, which calls the foreign function
     drop(vec);
 }
+
+#[test]
+fn test_new() {
+    const EMPTY: MyForeignVec = MyForeignVec::new();
+    assert!(EMPTY.is_empty());
+
+    let mut vecs: Vec<MyForeignVec> = vec![];
+    vecs.resize_with(2, Default::default);
+    vecs[0].get_vec().unwrap().push(1);
+
+    let taken = std::mem::take(&mut vecs[0]);
+    assert_eq!(taken, [1]);
+    assert_eq!(vecs[0].get_vec().unwrap().capacity(), 0);
+}
+
+#[test]
+fn test_index() {
+    fn first<C: std::ops::Index<usize, Output = i32>>(container: &C) -> i32 {
+        container[0]
+    }
+    let vec: MyForeignVec = vec![1, 2, 3].into();
+    assert_eq!(first(&vec), 1);
+    assert_eq!(vec[1..], [2, 3]);
+    assert_eq!(vec[..=1], [1, 2]);
+}
+
+#[cfg(not(any(feature = "hooks", feature = "protect", feature = "registry")))]
+#[test]
+fn test_size() {
+    use std::mem::size_of;
+    assert_eq!(size_of::<ForeignVec<(), i32>>(), 3 * size_of::<usize>());
+    assert_eq!(
+        size_of::<Option<ForeignVec<(), i32>>>(),
+        size_of::<ForeignVec<(), i32>>()
+    );
+    assert_eq!(
+        size_of::<MyForeignVec>(),
+        3 * size_of::<usize>() + size_of::<Foreign>()
+    );
+}
+
+// the imported region costs two words per buffer
+#[cfg(all(feature = "hooks", not(any(feature = "protect", feature = "registry"))))]
+#[test]
+fn test_size_hooks() {
+    use std::mem::size_of;
+    assert_eq!(size_of::<ForeignVec<(), i32>>(), 5 * size_of::<usize>());
+}
+
+#[test]
+fn test_zero_sized() {
+    let mut vec: ForeignVec<(), ()> = vec![(); 3].into();
+    assert!(vec.get_vec().is_some());
+    vec.get_vec().unwrap().push(());
+    assert_eq!(vec.len(), 4);
+}
+
+#[test]
+fn test_take_replace() {
+    struct Array {
+        values: MyForeignVec,
+    }
+    let mut array = Array {
+        values: vec![1, 2].into(),
+    };
+    let values = array.values.take();
+    assert_eq!(values, [1, 2]);
+    assert!(array.values.is_empty());
+
+    let old = array.values.replace(vec![3].into());
+    assert!(old.is_empty());
+    assert_eq!(array.values, [3]);
+}
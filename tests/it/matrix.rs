@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use foreign_vec::matrix::MatrixError;
+use foreign_vec::{ForeignMatrix, ForeignVec};
+
+use super::import_arc;
+
+#[test]
+fn basics() {
+    // a 2x3 block of a 2x4 matrix
+    let values = vec![1, 2, 3, 0, 4, 5, 6];
+    let matrix = ForeignMatrix::<(), _>::try_new(values.into(), 2, 3, 4).unwrap();
+    assert_eq!(matrix.rows(), 2);
+    assert_eq!(matrix.cols(), 3);
+    assert_eq!(matrix.row(0), [1, 2, 3]);
+    assert_eq!(matrix.get(1), Some(&[4, 5, 6][..]));
+    assert_eq!(matrix.get(2), None);
+    assert_eq!(
+        matrix.iter_rows().rev().collect::<Vec<_>>(),
+        [[4, 5, 6], [1, 2, 3]]
+    );
+    assert_eq!(format!("{matrix:?}"), "[[1, 2, 3], [4, 5, 6]]");
+}
+
+#[test]
+fn invalid() {
+    let values = || ForeignVec::<(), _>::from(vec![0; 6]);
+    assert_eq!(
+        ForeignMatrix::try_new(values(), 2, 3, 2).map(|_| ()),
+        Err(MatrixError::StrideTooSmall {
+            row_stride: 2,
+            cols: 3
+        })
+    );
+    assert_eq!(
+        ForeignMatrix::try_new(values(), 2, 3, 4).map(|_| ()),
+        Err(MatrixError::OutOfBounds {
+            required: Some(7),
+            values: 6
+        })
+    );
+    assert_eq!(
+        ForeignMatrix::try_new(values(), usize::MAX, 1, 2).map(|_| ()),
+        Err(MatrixError::OutOfBounds {
+            required: None,
+            values: 6
+        })
+    );
+    // empty matrices need no values
+    assert!(ForeignMatrix::try_new(values(), 0, 10, 10).is_ok());
+    assert!(ForeignMatrix::try_new(values(), 10, 0, 0).is_ok());
+}
+
+#[test]
+fn row_shared() {
+    let data: Arc<[i32]> = vec![1, 2, 3, 4].into();
+    let values = import_arc(&data);
+    let matrix = ForeignMatrix::try_new(values, 2, 2, 2).unwrap();
+
+    let row = matrix.row_shared(1);
+    assert!(row.is_foreign());
+    assert_eq!(row, [3, 4]);
+    assert_eq!(row.as_ptr(), data[2..].as_ptr());
+    drop(matrix);
+    assert_eq!(row, [3, 4]);
+}
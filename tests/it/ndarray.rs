@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use ndarray::{array, s, Array1};
+
+use super::{import_arc, MyForeignVec};
+
+#[test]
+fn view() {
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    let vec = import_arc(&data);
+    let view = vec.as_array_view();
+    assert_eq!(view.sum(), 6);
+    assert_eq!(view.as_ptr(), data.as_ptr());
+
+    let cow = vec.as_cow_array();
+    assert!(cow.is_view());
+    assert_eq!(&cow * 2, array![2, 4, 6]);
+}
+
+#[test]
+fn from_array() {
+    let array = array![1, 2, 3];
+    let ptr = array.as_ptr();
+    let vec = MyForeignVec::from(array);
+    assert_eq!(vec, [1, 2, 3]);
+    assert_eq!(vec.as_ptr(), ptr);
+
+    // sliced
+    let mut array = array![1, 2, 3, 4];
+    array.slice_collapse(s![1..3]);
+    assert_eq!(MyForeignVec::from(array), [2, 3]);
+
+    // strided
+    let mut array = Array1::from(vec![1, 2, 3, 4]);
+    array.slice_collapse(s![..;2]);
+    assert_eq!(MyForeignVec::from(array), [1, 3]);
+
+    assert!(MyForeignVec::from(Array1::<i32>::zeros(0)).is_empty());
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use foreign_vec::OwnerHandle;
+
+#[test]
+fn single_release() {
+    // validity, offsets and values released by a single callback, here mocked by an `Arc`
+    let release = Arc::new(());
+    let handle = OwnerHandle::new(release.clone());
+    let (validity, offsets, values) = ([0b101u8], [0i32, 1, 1, 3], *b"abc");
+    let validity = unsafe { handle.import(validity.as_ptr(), validity.len()) };
+    let offsets = unsafe { handle.import(offsets.as_ptr(), offsets.len()) };
+    let values = unsafe { handle.import(values.as_ptr(), values.len()) };
+    drop(handle);
+    assert_eq!(Arc::strong_count(&release), 2);
+
+    drop(offsets);
+    drop(validity);
+    let shared = values.clone_shared();
+    drop(values);
+    assert_eq!(shared, *b"abc");
+    assert_eq!(Arc::strong_count(&release), 2);
+    drop(shared);
+    assert_eq!(Arc::strong_count(&release), 1);
+}
@@ -0,0 +1,31 @@
+use proptest::prelude::*;
+
+use foreign_vec::proptest::foreign_vec;
+
+proptest! {
+    #[test]
+    fn length(vec in foreign_vec(any::<u8>(), 1..10)) {
+        prop_assert!((1..10).contains(&vec.len()));
+    }
+
+    #[test]
+    fn clone_shared(vec in foreign_vec(any::<i64>(), 0..10)) {
+        let cloned = vec.clone_shared();
+        prop_assert_eq!(cloned.is_foreign(), vec.is_foreign());
+        prop_assert_eq!(cloned, vec);
+    }
+}
+
+#[test]
+fn both_modes() {
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    let strategy = foreign_vec(any::<u8>(), 0..4);
+    let mut runner = TestRunner::deterministic();
+    let foreign = (0..64)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .filter(|vec| vec.is_foreign())
+        .count();
+    assert!(foreign > 0 && foreign < 64);
+}
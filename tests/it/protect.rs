@@ -0,0 +1,136 @@
+use std::alloc::{alloc_zeroed, dealloc, Layout};
+use std::rc::Rc;
+
+use foreign_vec::{ForeignVec, Owner};
+
+const PAGE: usize = 4096;
+
+/// A page-aligned region that is written to when released, as some allocators do.
+struct Pages {
+    ptr: *mut u8,
+    layout: Layout,
+}
+
+impl Pages {
+    fn new(pages: usize) -> Self {
+        let layout = Layout::from_size_align(pages * PAGE, PAGE).unwrap();
+        let ptr = unsafe { alloc_zeroed(layout) };
+        assert!(!ptr.is_null());
+        Self { ptr, layout }
+    }
+}
+
+impl Drop for Pages {
+    fn drop(&mut self) {
+        unsafe {
+            self.ptr.write(1);
+            dealloc(self.ptr, self.layout)
+        };
+    }
+}
+
+impl Owner for Pages {}
+
+/// Returns the permissions of the mapping containing `ptr`, as in `/proc/self/maps`.
+fn permissions(ptr: *const u8) -> String {
+    let address = ptr as usize;
+    let maps = std::fs::read_to_string("/proc/self/maps").unwrap();
+    maps.lines()
+        .find_map(|line| {
+            let mut fields = line.split(' ');
+            let (start, end) = fields.next()?.split_once('-')?;
+            let start = usize::from_str_radix(start, 16).ok()?;
+            let end = usize::from_str_radix(end, 16).ok()?;
+            (start <= address && address < end).then(|| fields.next().unwrap().to_string())
+        })
+        .unwrap()
+}
+
+#[cfg_attr(miri, ignore)] // miri does not support `mprotect`
+#[test]
+fn protect() {
+    let pages = Pages::new(2);
+    let ptr = pages.ptr;
+    let vec = unsafe { ForeignVec::from_foreign(ptr as *const u8, 2 * PAGE, pages) };
+    assert!(permissions(ptr).starts_with("r--"));
+    assert_eq!(vec[0], 0);
+    // the region is writable again when the owner is dropped
+    drop(vec);
+}
+
+#[cfg_attr(miri, ignore)] // miri does not support `mprotect`
+#[test]
+fn as_mut_slice_unchecked() {
+    let pages = Pages::new(1);
+    let ptr = pages.ptr;
+    let mut vec = unsafe { ForeignVec::from_foreign(ptr as *const u8, PAGE, pages) };
+    let slice = unsafe { vec.as_mut_slice_unchecked() };
+    slice[0] = 2;
+    assert!(permissions(ptr).starts_with("rw-"));
+    assert_eq!(vec[0], 2);
+}
+
+#[cfg_attr(miri, ignore)] // miri does not support `mprotect`
+#[test]
+fn partial_pages() {
+    // only pages fully in the region are protected
+    let pages = Pages::new(2);
+    let ptr = pages.ptr;
+    let vec = unsafe { ForeignVec::from_foreign(ptr as *const u8, PAGE + 1, pages) };
+    assert!(permissions(ptr).starts_with("r--"));
+    assert!(permissions(ptr.wrapping_add(PAGE)).starts_with("rw-"));
+    drop(vec);
+
+    // regions that do not start at a page are not protected
+    let pages = Pages::new(2);
+    let ptr = pages.ptr.wrapping_add(1);
+    let vec = unsafe { ForeignVec::from_foreign(ptr as *const u8, PAGE, pages) };
+    assert!(permissions(ptr).starts_with("rw-"));
+    drop(vec);
+}
+
+#[repr(align(4096))]
+struct Aligned([u8; 2 * PAGE]);
+
+/// Read-only pages, in `.rodata`
+static RODATA: Aligned = Aligned([1; 2 * PAGE]);
+
+#[cfg_attr(miri, ignore)] // miri does not support `mprotect`
+#[test]
+fn read_only() {
+    // pages that are not writable are left as they are, and are not made writable
+    let ptr = RODATA.0.as_ptr();
+    let vec = unsafe { ForeignVec::from_foreign(ptr, RODATA.0.len(), ()) };
+    assert!(!permissions(ptr).starts_with("rw"));
+    drop(vec);
+    assert!(!permissions(ptr).starts_with("rw"));
+}
+
+#[cfg_attr(miri, ignore)] // miri does not support `mprotect`
+#[test]
+fn restore() {
+    // the original protection is restored, and the owner can write to the region
+    let pages = Pages::new(1);
+    let ptr = pages.ptr;
+    let vec = unsafe { ForeignVec::from_foreign(ptr as *const u8, PAGE, ()) };
+    assert!(permissions(ptr).starts_with("r--"));
+    drop(vec);
+    assert!(permissions(ptr).starts_with("rw-"));
+    drop(pages);
+}
+
+#[cfg_attr(miri, ignore)] // miri does not support `mprotect`
+#[test]
+fn shared() {
+    // only the buffer that imported the region restores it, even if a clone outlives it
+    let pages = Rc::new(Pages::new(1));
+    let ptr = pages.ptr;
+    let vec = unsafe { ForeignVec::from_foreign(ptr as *const u8, PAGE, pages) };
+    let clone = vec.clone_shared();
+    assert!(permissions(ptr).starts_with("r--"));
+    drop(vec);
+    assert!(permissions(ptr).starts_with("rw-"));
+    assert_eq!(clone[0], 0);
+    // the owner writes to the region when released
+    drop(clone);
+}
@@ -0,0 +1,29 @@
+use rayon::prelude::*;
+
+use super::{into_raw_parts, Foreign, MyForeignVec};
+
+#[test]
+fn par_iter() {
+    let (ptr, length, capacity) = into_raw_parts((0..1000).collect());
+    let a = Foreign {
+        ptr,
+        length,
+        capacity,
+    };
+    let vec = unsafe { MyForeignVec::from_foreign(a.ptr, a.length, a) };
+
+    assert_eq!(vec.par_iter().sum::<i32>(), 499500);
+    assert_eq!(
+        vec.par_chunks(100).map(|chunk| chunk.len()).sum::<usize>(),
+        1000
+    );
+}
+
+#[test]
+fn generic() {
+    fn sum<'a, I: IntoParallelIterator<Item = &'a i32>>(iter: I) -> i32 {
+        iter.into_par_iter().sum()
+    }
+    let vec: MyForeignVec = vec![1, 2, 3].into();
+    assert_eq!(sum(&vec), 6);
+}
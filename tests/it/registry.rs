@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use foreign_vec::registry::live_regions;
+use foreign_vec::{ForeignVec, ImportError};
+
+use super::import_arc;
+
+fn is_live(data: &[i32]) -> bool {
+    let ptr = data.as_ptr() as *const u8;
+    live_regions().iter().any(|(start, _)| *start == ptr)
+}
+
+#[test]
+fn records() {
+    let data: Arc<[i32]> = vec![1, 2, 3, 4].into();
+    let vec = import_arc(&data);
+    assert!(is_live(&data));
+
+    // sharing a region is not importing it
+    let chunks = vec.chunks_shared(2).collect::<Vec<_>>();
+    let clone = vec.clone_shared();
+    drop(vec);
+    assert!(!is_live(&data));
+    drop((chunks, clone));
+
+    // a region may be imported again once released
+    let vec = import_arc(&data);
+    assert!(is_live(&data));
+    drop(vec);
+    assert!(!is_live(&data));
+}
+
+#[test]
+fn overlap() {
+    let data: Arc<[i32]> = vec![1, 2, 3, 4].into();
+    let vec = unsafe { ForeignVec::from_foreign(data.as_ptr(), 2, data.clone()) };
+
+    // adjacent regions do not overlap
+    let other = unsafe { ForeignVec::from_foreign(data[2..].as_ptr(), 2, data.clone()) };
+    drop(other);
+
+    let result = unsafe { ForeignVec::try_from_foreign(data[1..].as_ptr(), 2, data.clone()) };
+    assert_eq!(result.map(|_| ()), Err(ImportError::Overlapping));
+    let result = std::panic::catch_unwind(|| {
+        let _ = unsafe { ForeignVec::from_foreign(data[1..].as_ptr(), 2, data.clone()) };
+    });
+    assert!(result.is_err());
+    // the overlapping region is not recorded
+    assert!(is_live(&data) && !is_live(&data[1..]));
+    drop(vec);
+    assert!(!is_live(&data));
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn cast() {
+    // regions that are not recorded remain so when cast
+    static TABLE: [i32; 2] = [1, 2];
+    let a = ForeignVec::from_static(&TABLE).try_cast::<u8>().unwrap();
+    let b = ForeignVec::from_static(&TABLE).try_cast::<u8>().unwrap();
+    assert!(!is_live(&TABLE));
+    drop((a, b));
+
+    // recorded regions remain recorded until the cast buffer is dropped
+    let data: Arc<[i32]> = vec![1, 2, 3, 4].into();
+    let vec = import_arc(&data).try_cast::<u8>().unwrap();
+    assert!(is_live(&data));
+
+    // and shared regions are not recorded when cast
+    let chunks = vec
+        .chunks_shared(8)
+        .map(|chunk| chunk.try_cast::<i32>().unwrap())
+        .collect::<Vec<_>>();
+    let clone = vec.clone_shared().try_cast::<i32>().unwrap();
+    drop(vec);
+    assert!(!is_live(&data));
+    assert_eq!(chunks, [&[1, 2][..], &[3, 4]]);
+    assert_eq!(clone, [1, 2, 3, 4]);
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use foreign_vec::ForeignVec;
+use rkyv::rancor::Error;
+use rkyv::rend::i32_le;
+use rkyv::util::AlignedVec;
+use rkyv::vec::ArchivedVec;
+
+use super::{into_raw_parts, Foreign, MyForeignVec};
+
+#[test]
+fn roundtrip() {
+    let (ptr, length, capacity) = into_raw_parts(vec![1, 2, 3]);
+    let a = Foreign {
+        ptr,
+        length,
+        capacity,
+    };
+    let vec = unsafe { MyForeignVec::from_foreign(a.ptr, a.length, a) };
+
+    let bytes = rkyv::to_bytes::<Error>(&vec).unwrap();
+    let archived = unsafe { rkyv::access_unchecked::<ArchivedVec<i32_le>>(&bytes) };
+    assert_eq!(archived.as_slice(), &[1, 2, 3]);
+
+    let mut vec: MyForeignVec = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(&*vec, &[1, 2, 3]);
+    // deserialization always allocates natively
+    assert!(vec.get_vec().is_some());
+}
+
+#[test]
+fn from_archived() {
+    let vec: ForeignVec<(), u8> = b"abc".to_vec().into();
+    let bytes: Arc<AlignedVec> = Arc::new(rkyv::to_bytes::<Error>(&vec).unwrap());
+
+    let archived = unsafe { rkyv::access_unchecked::<ArchivedVec<u8>>(&bytes) };
+    let mut vec = unsafe { ForeignVec::from_archived(archived, bytes.clone()) };
+    drop(bytes);
+    assert_eq!(&*vec, b"abc");
+    assert!(vec.get_vec().is_none());
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use foreign_vec::{ForeignVec, SegmentedForeignVec};
+
+use super::import_arc;
+
+fn segments(data: &Arc<[i32]>) -> SegmentedForeignVec<Arc<[i32]>, i32> {
+    [
+        vec![1, 2].into(),
+        ForeignVec::new(),
+        import_arc(data),
+        vec![5].into(),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn index() {
+    let data: Arc<[i32]> = vec![3, 4].into();
+    let vec = segments(&data);
+    assert_eq!(vec.len(), 5);
+    assert_eq!(vec.segments().len(), 4);
+    assert_eq!((0..5).map(|i| vec[i]).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert_eq!(vec.get(5), None);
+    assert_eq!(
+        vec.iter().rev().copied().collect::<Vec<_>>(),
+        [5, 4, 3, 2, 1]
+    );
+    assert_eq!(format!("{vec:?}"), "[1, 2, 3, 4, 5]");
+
+    // the foreign segment is not copied
+    assert_eq!(&vec[2] as *const i32, data.as_ptr());
+
+    let vec = SegmentedForeignVec::from(vec.into_segments());
+    assert_eq!(vec[4], 5);
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+    let vec = SegmentedForeignVec::<(), i32>::from(vec![vec![1].into()]);
+    let _ = vec[1];
+}
+
+#[test]
+fn coalesce() {
+    let data: Arc<[i32]> = vec![3, 4].into();
+    let vec = segments(&data).coalesce();
+    assert!(!vec.is_foreign());
+    assert_eq!(vec, [1, 2, 3, 4, 5]);
+
+    assert!(SegmentedForeignVec::<(), i32>::new().coalesce().is_empty());
+}
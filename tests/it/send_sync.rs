@@ -0,0 +1,65 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use foreign_vec::ForeignVec;
+
+use super::Foreign;
+
+const fn assert_send<T: Send>() {}
+const fn assert_sync<T: Sync>() {}
+
+/// Fails to compile if `T` implements `Trait`, like `static_assertions::assert_not_impl_any`:
+/// the call to `some_item` is ambiguous if and only if both impls apply.
+macro_rules! assert_not_impl {
+    ($t:ty: $trait:path) => {
+        const _: fn() = || {
+            trait AmbiguousIfImpl<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+            struct Invalid;
+            impl<T: ?Sized + $trait> AmbiguousIfImpl<Invalid> for T {}
+            let _ = <$t as AmbiguousIfImpl<_>>::some_item;
+        };
+    };
+}
+
+const _: () = {
+    assert_send::<ForeignVec<(), i32>>();
+    assert_sync::<ForeignVec<(), i32>>();
+    assert_send::<ForeignVec<Arc<[i32]>, i32>>();
+    assert_sync::<ForeignVec<Arc<[i32]>, i32>>();
+    // `Cell` is `Send` but not `Sync`
+    assert_send::<ForeignVec<(), Cell<i32>>>();
+    assert_send::<ForeignVec<Cell<i32>, i32>>();
+};
+
+// `Foreign` holds a raw pointer and is thus neither `Send` nor `Sync`
+assert_not_impl!(ForeignVec<Foreign, i32>: Send);
+assert_not_impl!(ForeignVec<Foreign, i32>: Sync);
+assert_not_impl!(ForeignVec<Rc<[i32]>, i32>: Send);
+assert_not_impl!(ForeignVec<Rc<[i32]>, i32>: Sync);
+assert_not_impl!(ForeignVec<(), Rc<i32>>: Send);
+assert_not_impl!(ForeignVec<(), Rc<i32>>: Sync);
+assert_not_impl!(ForeignVec<(), Cell<i32>>: Sync);
+assert_not_impl!(ForeignVec<Cell<i32>, i32>: Sync);
+
+#[test]
+fn across_threads() {
+    let vec: ForeignVec<Arc<[i32]>, i32> = Arc::<[i32]>::from(vec![1, 2, 3]).into();
+    let shared = Arc::new(vec);
+    let handles = (0..2)
+        .map(|_| {
+            let shared = shared.clone();
+            std::thread::spawn(move || shared.iter().sum::<i32>())
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 6);
+    }
+
+    let vec = Arc::into_inner(shared).unwrap();
+    let vec = std::thread::spawn(move || vec).join().unwrap();
+    assert_eq!(vec, [1, 2, 3]);
+}
@@ -0,0 +1,20 @@
+use super::{into_raw_parts, Foreign, MyForeignVec};
+
+#[test]
+fn roundtrip() {
+    let (ptr, length, capacity) = into_raw_parts(vec![1, 2, 3]);
+    let a = Foreign {
+        ptr,
+        length,
+        capacity,
+    };
+    let vec = unsafe { MyForeignVec::from_foreign(a.ptr, a.length, a) };
+
+    let json = serde_json::to_string(&vec).unwrap();
+    assert_eq!(json, "[1,2,3]");
+
+    let mut vec: MyForeignVec = serde_json::from_str(&json).unwrap();
+    assert_eq!(&*vec, &[1, 2, 3]);
+    // deserialization always allocates natively
+    assert!(vec.get_vec().is_some());
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use foreign_vec::{ForeignSlice, ForeignVec, ImportError};
+
+/// A foreign function that loans a region to `callback` for the duration of the call.
+fn with_loan<R>(callback: impl FnOnce(ForeignSlice<'_, i32>) -> R) -> R {
+    let data = [1, 2, 3];
+    callback(unsafe { ForeignSlice::from_raw_parts(data.as_ptr(), data.len()) })
+}
+
+#[test]
+fn loan() {
+    let sum = with_loan(|slice| slice.iter().sum::<i32>());
+    assert_eq!(sum, 6);
+
+    // copied out of the loan
+    let vec: ForeignVec<(), _> = with_loan(|slice| slice.to_native());
+    assert_eq!(vec, [1, 2, 3]);
+    assert!(!vec.is_foreign());
+}
+
+#[test]
+fn into_foreign() {
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    let slice = ForeignSlice::from(&data[..]);
+    assert_eq!(format!("{slice:?}"), "[1, 2, 3]");
+
+    // attaching an owner that keeps the region alive
+    let vec = unsafe { slice.into_foreign(data.clone()) };
+    assert!(vec.is_foreign());
+    assert_eq!(vec.as_ptr(), data.as_ptr());
+    drop(data);
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+fn validates() {
+    let slice = unsafe { ForeignSlice::<i32>::from_raw_parts(std::ptr::null(), 0) };
+    assert!(slice.is_empty());
+
+    let slice = unsafe { ForeignSlice::<i32>::try_from_raw_parts(std::ptr::null(), 1) };
+    assert_eq!(slice.map(|_| ()), Err(ImportError::Null));
+}
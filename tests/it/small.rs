@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use foreign_vec::SmallForeignVec;
+
+use super::import_arc;
+
+type Small = SmallForeignVec<(), String, 2>;
+
+#[test]
+fn inline() {
+    let mut vec = Small::new();
+    vec.push("a".to_string());
+    vec.push("b".to_string());
+    assert!(vec.is_inline());
+    assert_eq!(*vec, ["a", "b"]);
+
+    // spills once full
+    vec.push("c".to_string());
+    assert!(!vec.is_inline());
+    assert_eq!(*vec, ["a", "b", "c"]);
+    assert_eq!(vec.into_foreign_vec(), ["a", "b", "c"]);
+
+    let vec = Small::from_slice(&["a".to_string()]);
+    assert!(vec.is_inline());
+    let vec = vec.into_foreign_vec();
+    assert!(!vec.is_foreign());
+    assert_eq!(vec, ["a"]);
+
+    let vec = Small::from_slice(&["a".to_string(), "b".to_string(), "c".to_string()]);
+    assert!(!vec.is_inline());
+}
+
+#[test]
+fn from() {
+    let vec = ["a", "b"].map(String::from).into_iter().collect::<Small>();
+    assert!(vec.is_inline());
+    assert_eq!(format!("{vec:?}"), r#"["a", "b"]"#);
+
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    let foreign = import_arc(&data);
+    let mut vec = SmallForeignVec::<_, _, 8>::from(foreign);
+    assert!(!vec.is_inline());
+    vec.push(4);
+    assert_eq!(*vec, [1, 2, 3, 4]);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn zero_capacity() {
+    let mut vec = SmallForeignVec::<(), i32, 0>::new();
+    assert!(vec.is_inline());
+    vec.push(1);
+    assert!(!vec.is_inline());
+    assert_eq!(*vec, [1]);
+}
@@ -0,0 +1,15 @@
+use stable_deref_trait::StableDeref;
+
+use super::MyForeignVec;
+
+fn deref_address<S: StableDeref>(value: &S) -> *const () {
+    &**value as *const S::Target as *const ()
+}
+
+#[test]
+fn stable_across_moves() {
+    let vec: MyForeignVec = vec![1, 2, 3].into();
+    let before = deref_address(&vec);
+    let moved = Box::new(vec);
+    assert_eq!(before, deref_address(&*moved));
+}
@@ -0,0 +1,37 @@
+use foreign_vec::testing::Tracker;
+
+#[test]
+fn no_leaks() {
+    let tracker = Tracker::new();
+    let vec = tracker.import(&[1, 2, 3]);
+    let shared = vec.clone_shared();
+    assert_eq!(tracker.live(), 2);
+    assert_eq!(shared, [1, 2, 3]);
+
+    drop(vec);
+    assert_eq!(shared, [1, 2, 3]);
+    drop(shared);
+    assert_eq!(tracker.released(), 2);
+    tracker.assert_no_leaks();
+}
+
+#[test]
+// the leak is deliberate
+#[cfg_attr(miri, ignore)]
+#[should_panic(expected = "1 owner(s) were not released")]
+fn leak() {
+    let tracker = Tracker::new();
+    std::mem::forget(tracker.import(&[1]));
+    tracker.assert_no_leaks();
+}
+
+#[test]
+#[should_panic(expected = "released more than once")]
+fn double_release() {
+    let tracker = Tracker::new();
+    let owner = std::mem::ManuallyDrop::new(tracker.owner());
+    // each copy releases its reference to the tracker
+    std::mem::forget(tracker.clone());
+    let _first = unsafe { std::ptr::read(&*owner) };
+    let _second = unsafe { std::ptr::read(&*owner) };
+}
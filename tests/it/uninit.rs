@@ -0,0 +1,33 @@
+use std::mem::MaybeUninit;
+
+use foreign_vec::ForeignVec;
+
+#[test]
+fn foreign() {
+    // a region whose tail is initialized later
+    let mut data = [MaybeUninit::<i32>::uninit(); 4];
+    data[0].write(1);
+    data[1].write(2);
+    let ptr = data.as_mut_ptr() as *mut i32;
+
+    let mut vec = unsafe { ForeignVec::from_foreign_uninit(ptr, 4, ()) };
+    assert_eq!(vec.len(), 4);
+    assert!(vec.is_foreign());
+
+    // e.g. the foreign side initializes the rest of the region
+    unsafe { ptr.add(2).write(3) };
+    vec.truncate(3);
+    let vec = unsafe { vec.assume_init() };
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+fn native() {
+    let mut data = Vec::with_capacity(2);
+    data.push(MaybeUninit::new(1u8));
+    let vec: ForeignVec<(), MaybeUninit<u8>> = data.into();
+    let mut vec = unsafe { vec.assume_init() };
+    assert_eq!(vec, [1]);
+    vec.get_vec().unwrap().push(2);
+    assert_eq!(vec, [1, 2]);
+}
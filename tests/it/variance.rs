@@ -0,0 +1,31 @@
+use foreign_vec::ForeignVec;
+
+// compile-time checks that `ForeignVec` is covariant in both `D` and `T`, like `(D, Vec<T>)`
+#[allow(dead_code)]
+fn covariant_in_t<'a, D>(vec: ForeignVec<D, &'static u8>) -> ForeignVec<D, &'a u8> {
+    vec
+}
+
+#[allow(dead_code)]
+fn covariant_in_d<'a, T>(vec: ForeignVec<&'static u8, T>) -> ForeignVec<&'a u8, T> {
+    vec
+}
+
+#[test]
+fn shorter_lifetime() {
+    let value = 1u8;
+    let vec: ForeignVec<(), &'static u8> = vec![&0u8].into();
+    let mut vec: ForeignVec<(), &u8> = vec;
+    vec.make_mut().push(&value);
+    assert_eq!(vec.as_ref(), &[&0, &1]);
+}
+
+#[cfg(foreign_vec_nightly)]
+#[test]
+fn may_dangle() {
+    // as for `Vec`, `vec` may hold references to values dropped before it
+    let mut vec: ForeignVec<(), &u8> = ForeignVec::new();
+    let value = 1u8;
+    vec.make_mut().push(&value);
+    assert_eq!(vec.as_ref(), &[&1]);
+}
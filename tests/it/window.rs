@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use foreign_vec::ForeignVec;
+
+use super::{import_arc, MyForeignVec};
+
+#[test]
+fn advance() {
+    let data: Arc<[i32]> = vec![1, 2, 3, 4].into();
+    let mut vec = import_arc(&data);
+    vec.advance(1);
+    assert_eq!(vec, [2, 3, 4]);
+    assert_eq!(vec.as_ptr(), data[1..].as_ptr());
+    vec.advance(3);
+    assert!(vec.is_empty());
+
+    let mut vec: MyForeignVec = vec![1, 2, 3].into();
+    vec.advance(2);
+    assert_eq!(vec, [3]);
+    assert!(vec.get_vec().is_some());
+}
+
+#[test]
+#[should_panic]
+fn advance_out_of_bounds() {
+    let mut vec: MyForeignVec = vec![1, 2].into();
+    vec.advance(3);
+}
+
+#[test]
+fn truncate() {
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    let mut vec = import_arc(&data);
+    vec.truncate(5);
+    assert_eq!(vec, [1, 2, 3]);
+    vec.truncate(1);
+    assert_eq!(vec, [1]);
+    assert_eq!(vec.as_ptr(), data.as_ptr());
+
+    let mut vec: MyForeignVec = vec![1, 2, 3].into();
+    vec.truncate(2);
+    assert_eq!(vec, [1, 2]);
+}
+
+#[test]
+fn split_off() {
+    let data: Arc<[i32]> = vec![1, 2, 3].into();
+    let mut vec = import_arc(&data);
+    let tail = vec.split_off(1);
+    assert_eq!(vec, [1]);
+    assert_eq!(tail, [2, 3]);
+    assert_eq!(tail.as_ptr(), data[1..].as_ptr());
+    assert_eq!(Arc::strong_count(&data), 3);
+    drop(vec);
+    drop(tail);
+    assert_eq!(Arc::strong_count(&data), 1);
+
+    let mut vec: ForeignVec<Arc<[i32]>, i32> = vec![1, 2, 3].into();
+    assert!(vec.split_off(3).is_empty());
+    assert_eq!(vec.split_off(0), [1, 2, 3]);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn chunks_shared() {
+    let data: Arc<[i32]> = vec![1, 2, 3, 4, 5].into();
+    let chunks = import_arc(&data).chunks_shared(2).collect::<Vec<_>>();
+    assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+    assert_eq!(chunks[1].as_ptr(), data[2..].as_ptr());
+    assert_eq!(Arc::strong_count(&data), 4);
+
+    // chunks are processed independently
+    let sums = chunks
+        .into_iter()
+        .map(|chunk| std::thread::spawn(move || chunk.iter().sum::<i32>()))
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, [3, 7, 5]);
+    assert_eq!(Arc::strong_count(&data), 1);
+
+    let vec: ForeignVec<Arc<[i32]>, i32> = vec![1, 2, 3].into();
+    let mut chunks = vec.chunks_shared(2);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.next_back().unwrap(), [3]);
+    assert!(!chunks.next().unwrap().is_foreign());
+}
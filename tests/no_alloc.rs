@@ -0,0 +1,44 @@
+//! Tests of the foreign-only mode, i.e. without the `alloc` feature.
+#![cfg(not(feature = "alloc"))]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use foreign_vec::{ForeignVec, Owner};
+
+/// An owner of a region that it does not deallocate, e.g. a DMA buffer.
+struct Dma<'a> {
+    released: &'a AtomicUsize,
+}
+
+impl Drop for Dma<'_> {
+    fn drop(&mut self) {
+        self.released.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl Owner for Dma<'_> {}
+
+#[test]
+fn foreign() {
+    let region = [1u8, 2, 3, 4];
+    let released = AtomicUsize::new(0);
+    let owner = Dma {
+        released: &released,
+    };
+    let mut vec = unsafe { ForeignVec::from_foreign(region.as_ptr(), region.len(), owner) };
+    assert!(vec.is_foreign());
+    assert_eq!(vec, [1, 2, 3, 4]);
+    vec.advance(1);
+    vec.truncate(2);
+    assert_eq!(&*vec, &[2, 3]);
+    drop(vec);
+    assert_eq!(released.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn empty() {
+    let vec = ForeignVec::<(), u8>::new();
+    assert!(vec.is_empty());
+    assert!(!vec.is_foreign());
+    assert!(vec.leak().is_empty());
+}